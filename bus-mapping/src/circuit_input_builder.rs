@@ -14,7 +14,7 @@ use self::access::gen_state_access_trace;
 use crate::error::Error;
 use crate::evm::opcodes::{gen_associated_ops, gen_begin_tx_ops, gen_end_tx_ops};
 use crate::operation::{CallContextField, Operation, RWCounter, StartOp, RW};
-use crate::rpc::GethClient;
+use crate::rpc::{GethClient, RetryConfig};
 use crate::state_db::{self, CodeDB, StateDB};
 pub use access::{Access, AccessSet, AccessValue, CodeSource};
 pub use block::{Block, BlockContext};
@@ -29,8 +29,10 @@ pub use execution::{
 };
 pub use input_state_ref::CircuitInputStateRef;
 use itertools::Itertools;
-use log::warn;
+use log::{debug, warn};
 use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
 pub use transaction::{Transaction, TransactionContext};
 
 /// Circuit Setup Parameters
@@ -145,7 +147,14 @@ impl<'a> CircuitInputBuilder {
             ),
         );
 
-        Transaction::new(call_id, &self.sdb, &mut self.code_db, eth_tx, is_success)
+        Transaction::new(
+            call_id,
+            &self.sdb,
+            &mut self.code_db,
+            eth_tx,
+            self.block.base_fee,
+            is_success,
+        )
     }
 
     /// Iterate over all generated CallContext RwCounterEndOfReversion
@@ -281,12 +290,16 @@ impl<'a> CircuitInputBuilder {
 }
 
 /// Return all the keccak inputs used during the processing of the current
-/// block.
+/// block, deduplicated.
 pub fn keccak_inputs(block: &Block, code_db: &CodeDB) -> Result<Vec<Vec<u8>>, Error> {
     let mut keccak_inputs = Vec::new();
     // Tx Circuit
     let txs: Vec<geth_types::Transaction> = block.txs.iter().map(|tx| tx.into()).collect();
     keccak_inputs.extend_from_slice(&keccak_inputs_tx_circuit(&txs, block.chain_id.as_u64())?);
+    // Signed messages of the txs, whose hashes are signed
+    for tx in txs.iter().filter(|tx| !is_unsigned(tx)) {
+        keccak_inputs.push(tx.sign_msg(block.chain_id.as_u64())?);
+    }
     // Bytecode Circuit
     for bytecode in code_db.0.values() {
         keccak_inputs.push(bytecode.clone());
@@ -295,7 +308,11 @@ pub fn keccak_inputs(block: &Block, code_db: &CodeDB) -> Result<Vec<Vec<u8>>, Er
     keccak_inputs.extend_from_slice(&block.sha3_inputs);
     // MPT Circuit
     // TODO https://github.com/privacy-scaling-explorations/zkevm-circuits/issues/696
-    Ok(keccak_inputs)
+    Ok(keccak_inputs.into_iter().unique().collect())
+}
+
+fn is_unsigned(tx: &geth_types::Transaction) -> bool {
+    tx.v == 0 && tx.r.is_zero() && tx.s.is_zero()
 }
 
 /// Generate the keccak inputs required by the SignVerify Chip from the
@@ -324,7 +341,7 @@ pub fn keccak_inputs_tx_circuit(
         .iter()
         .enumerate()
         .filter(|(i, tx)| {
-            if tx.v == 0 && tx.r.is_zero() && tx.s.is_zero() {
+            if is_unsigned(tx) {
                 warn!("tx {} is not signed, skipping tx circuit keccak input", i);
                 false
             } else {
@@ -365,6 +382,44 @@ pub fn get_call_memory_offset_length(step: &GethExecStep, nth: usize) -> Result<
 
 type EthBlock = eth_types::Block<eth_types::Transaction>;
 
+/// Time spent in each of the steps of [`BuilderClient::gen_inputs`], to tell
+/// whether generating the inputs of a block is network or CPU bound.
+#[derive(Debug, Clone, Default)]
+pub struct GenInputsProfile {
+    /// Step 1. Querying geth for the block, txs and traces (network).
+    pub get_block: Duration,
+    /// Step 2. Computing the state accesses from the traces.
+    pub get_state_accesses: Duration,
+    /// Step 3. Querying geth for the accessed accounts, storage and codes
+    /// (network).
+    pub get_state: Duration,
+    /// Step 4. Building the partial StateDB and CodeDB.
+    pub build_state_code_db: Duration,
+    /// Step 5. Generating the bus-mapping operations of every step.
+    pub gen_inputs_from_state: Duration,
+}
+
+impl GenInputsProfile {
+    /// Returns the name and duration of each step, in order.
+    pub fn phases(&self) -> [(&'static str, Duration); 5] {
+        [
+            ("get_block", self.get_block),
+            ("get_state_accesses", self.get_state_accesses),
+            ("get_state", self.get_state),
+            ("build_state_code_db", self.build_state_code_db),
+            ("gen_inputs_from_state", self.gen_inputs_from_state),
+        ]
+    }
+}
+
+/// Await `f`, adding the time it took to `duration`.
+async fn timed<T>(duration: &mut Duration, f: impl Future<Output = T>) -> T {
+    let start = Instant::now();
+    let result = f.await;
+    *duration += start.elapsed();
+    result
+}
+
 /// Struct that wraps a GethClient and contains methods to perform all the steps
 /// necessary to generate the circuit inputs for a block by querying geth for
 /// the necessary information and using the CircuitInputBuilder.
@@ -372,6 +427,7 @@ pub struct BuilderClient<P: JsonRpcClient> {
     cli: GethClient<P>,
     chain_id: Word,
     circuits_params: CircuitsParams,
+    retry: RetryConfig,
 }
 
 impl<P: JsonRpcClient> BuilderClient<P> {
@@ -380,23 +436,38 @@ impl<P: JsonRpcClient> BuilderClient<P> {
         client: GethClient<P>,
         circuits_params: CircuitsParams,
     ) -> Result<Self, Error> {
-        let chain_id = client.get_chain_id().await?;
+        let retry = RetryConfig::default();
+        let chain_id = retry.retry(|| client.get_chain_id()).await?;
 
         Ok(Self {
             cli: client,
             chain_id: chain_id.into(),
             circuits_params,
+            retry,
         })
     }
 
+    /// Set the retry policy of the queries made to geth, which defaults to
+    /// [`RetryConfig::default`].
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Step 1. Query geth for Block, Txs, TxExecTraces, history block hashes
     /// and previous state root.
     pub async fn get_block(
         &self,
         block_num: u64,
     ) -> Result<(EthBlock, Vec<eth_types::GethExecTrace>, Vec<Word>, Word), Error> {
-        let eth_block = self.cli.get_block_by_number(block_num.into()).await?;
-        let geth_traces = self.cli.trace_block_by_number(block_num.into()).await?;
+        let eth_block = self
+            .retry
+            .retry(|| self.cli.get_block_by_number(block_num.into()))
+            .await?;
+        let geth_traces = self
+            .retry
+            .retry(|| self.cli.trace_block_by_number(block_num.into()))
+            .await?;
 
         // fetch up to 256 blocks
         let mut n_blocks = std::cmp::min(256, block_num as usize);
@@ -407,7 +478,10 @@ impl<P: JsonRpcClient> BuilderClient<P> {
             n_blocks -= 1;
 
             // TODO: consider replacing it with `eth_getHeaderByHash`, it's faster
-            let header = self.cli.get_block_by_hash(next_hash).await?;
+            let header = self
+                .retry
+                .retry(|| self.cli.get_block_by_hash(next_hash))
+                .await?;
 
             // set the previous state root
             if prev_state_root.is_none() {
@@ -475,8 +549,11 @@ impl<P: JsonRpcClient> BuilderClient<P> {
             let mut keys: Vec<Word> = key_set.iter().cloned().collect();
             keys.sort();
             let proof = self
-                .cli
-                .get_proof(address, keys, (block_num - 1).into())
+                .retry
+                .retry(|| {
+                    self.cli
+                        .get_proof(address, keys.clone(), (block_num - 1).into())
+                })
                 .await
                 .unwrap();
             proofs.push(proof);
@@ -484,8 +561,8 @@ impl<P: JsonRpcClient> BuilderClient<P> {
         let mut codes: HashMap<Address, Vec<u8>> = HashMap::new();
         for address in access_set.code {
             let code = self
-                .cli
-                .get_code(address, (block_num - 1).into())
+                .retry
+                .retry(|| self.cli.get_code(address, (block_num - 1).into()))
                 .await
                 .unwrap();
             codes.insert(address, code);
@@ -557,19 +634,52 @@ impl<P: JsonRpcClient> BuilderClient<P> {
         ),
         Error,
     > {
-        let (eth_block, geth_traces, history_hashes, prev_state_root) =
-            self.get_block(block_num).await?;
-        let access_set = self.get_state_accesses(&eth_block, &geth_traces)?;
-        let (proofs, codes) = self.get_state(block_num, access_set).await?;
-        let (state_db, code_db) = self.build_state_code_db(proofs, codes);
-        let builder = self.gen_inputs_from_state(
-            state_db,
-            code_db,
-            &eth_block,
-            &geth_traces,
-            history_hashes,
-            prev_state_root,
-        )?;
+        let (builder, eth_block, profile) = self.gen_inputs_with_profile(block_num).await?;
+        debug!("gen_inputs of block {}: {:?}", block_num, profile);
         Ok((builder, eth_block))
     }
+
+    /// Perform all the steps to generate the circuit inputs, returning along
+    /// with them the time spent in each step.
+    pub async fn gen_inputs_with_profile(
+        &self,
+        block_num: u64,
+    ) -> Result<
+        (
+            CircuitInputBuilder,
+            eth_types::Block<eth_types::Transaction>,
+            GenInputsProfile,
+        ),
+        Error,
+    > {
+        let mut profile = GenInputsProfile::default();
+
+        let (eth_block, geth_traces, history_hashes, prev_state_root) =
+            timed(&mut profile.get_block, self.get_block(block_num)).await?;
+        let access_set = timed(&mut profile.get_state_accesses, async {
+            self.get_state_accesses(&eth_block, &geth_traces)
+        })
+        .await?;
+        let (proofs, codes) = timed(
+            &mut profile.get_state,
+            self.get_state(block_num, access_set),
+        )
+        .await?;
+        let (state_db, code_db) = timed(&mut profile.build_state_code_db, async {
+            self.build_state_code_db(proofs, codes)
+        })
+        .await;
+        let builder = timed(&mut profile.gen_inputs_from_state, async {
+            self.gen_inputs_from_state(
+                state_db,
+                code_db,
+                &eth_block,
+                &geth_traces,
+                history_hashes,
+                prev_state_root,
+            )
+        })
+        .await?;
+        Ok((builder, eth_block, profile))
+    }
 }
@@ -10,10 +10,11 @@ use eth_types::{
 };
 use gadgets::impl_expr;
 use halo2_proofs::plonk::Expression;
+use std::fmt;
 use strum_macros::EnumIter;
 
 /// An execution step of the EVM.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ExecStep {
     /// Execution state
     pub exec_state: ExecState,
@@ -79,6 +80,17 @@ impl ExecStep {
             Some(ExecError::OutOfGas(_) | ExecError::StackOverflow | ExecError::StackUnderflow)
         )
     }
+
+    /// Returns `true` if both steps are equal apart from the global counter
+    /// they were executed at, so that steps of traces whose counters got
+    /// renumbered can still be compared.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        let other = Self {
+            rwc: self.rwc,
+            ..other.clone()
+        };
+        *self == other
+    }
 }
 
 impl Default for ExecStep {
@@ -203,6 +215,15 @@ pub enum NumberOrHash {
     Hash(H256),
 }
 
+impl fmt::Display for NumberOrHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Hash(hash) => write!(f, "{:?}", hash),
+        }
+    }
+}
+
 /// Defines a copy event associated with EVM opcodes such as CALLDATACOPY,
 /// CODECOPY, CREATE, etc. More information:
 /// <https://github.com/privacy-scaling-explorations/zkevm-specs/blob/master/specs/copy-proof.md>.
@@ -232,6 +253,17 @@ pub struct CopyEvent {
 }
 
 impl CopyEvent {
+    /// Total number of bytes copied during this copy event
+    pub fn length(&self) -> u64 {
+        u64::try_from(self.bytes.len()).unwrap()
+    }
+
+    /// Number of bytes left to copy at step index, which goes down from
+    /// [`length`](Self::length) at the first step to 1 at the last one.
+    pub fn bytes_left(&self, step_index: usize) -> u64 {
+        (self.length() * 2 - u64::try_from(step_index).unwrap()) / 2
+    }
+
     /// rw counter at step index
     pub fn rw_counter(&self, step_index: usize) -> u64 {
         u64::try_from(self.rw_counter_start.0).unwrap() + self.rw_counter_increase(step_index)
@@ -263,6 +295,38 @@ impl CopyEvent {
     }
 }
 
+/// Summary of a copy event on a single line, to diff the copy events of a
+/// block against its trace:
+/// `src_type(src_id)[src_addr..src_addr_end] -> dst_type(dst_id)[dst_addr..]`
+/// followed by the log id if any, the starting rw counter and the copied bytes
+/// in hex.
+impl fmt::Display for CopyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?}({})[{:#x}..{:#x}] -> {:?}({})[{:#x}..{:#x}]",
+            self.src_type,
+            self.src_id,
+            self.src_addr,
+            self.src_addr_end,
+            self.dst_type,
+            self.dst_id,
+            self.dst_addr,
+            self.dst_addr + self.length()
+        )?;
+        if let Some(log_id) = self.log_id {
+            write!(f, " log {}", log_id)?;
+        }
+        let bytes = self.bytes.iter().map(|(byte, _)| *byte).collect::<Vec<_>>();
+        write!(
+            f,
+            " rw_counter {}: 0x{}",
+            self.rw_counter_start.0,
+            hex::encode(bytes)
+        )
+    }
+}
+
 /// Intermediary multiplication step, representing `a * b == d (mod 2^256)`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExpStep {
@@ -298,3 +362,61 @@ pub struct ExpEvent {
     /// Intermediate multiplication results.
     pub steps: Vec<ExpStep>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exec_step_semantic_eq() {
+        let steps = |rwc_start: usize| {
+            [OpcodeId::PUSH1, OpcodeId::PUSH1, OpcodeId::ADD]
+                .into_iter()
+                .enumerate()
+                .map(|(idx, op)| ExecStep {
+                    exec_state: ExecState::Op(op),
+                    pc: ProgramCounter(idx * 2),
+                    rwc: RWCounter(rwc_start + idx),
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>()
+        };
+        let (steps_a, steps_b) = (steps(1), steps(10));
+
+        assert_ne!(steps_a, steps_b);
+        assert!(steps_a
+            .iter()
+            .zip(steps_b.iter())
+            .all(|(a, b)| a.semantic_eq(b)));
+
+        let other_pc = ExecStep {
+            pc: ProgramCounter(1),
+            ..steps_b[0].clone()
+        };
+        assert!(!steps_a[0].semantic_eq(&other_pc));
+    }
+
+    #[test]
+    fn copy_event_display() {
+        let event = CopyEvent {
+            src_addr: 0x10,
+            src_addr_end: 0x12,
+            src_type: CopyDataType::Memory,
+            src_id: NumberOrHash::Number(1),
+            dst_addr: 0x20,
+            dst_type: CopyDataType::TxLog,
+            dst_id: NumberOrHash::Number(2),
+            log_id: Some(3),
+            rw_counter_start: RWCounter(7),
+            bytes: vec![(0xab, false), (0x01, false)],
+        };
+        assert_eq!(
+            event.to_string(),
+            "Memory(1)[0x10..0x12] -> TxLog(2)[0x20..0x22] log 3 rw_counter 7: 0xab01"
+        );
+        assert_eq!(
+            NumberOrHash::Hash(H256::zero()).to_string(),
+            format!("{:?}", H256::zero())
+        );
+    }
+}
@@ -240,6 +240,7 @@ impl<'a> CircuitInputStateRef<'a> {
         address: StackAddress,
         value: Word,
     ) -> Result<(), Error> {
+        check_stack_address(address)?;
         let call_id = self.call()?.call_id;
         self.push_op(step, RW::WRITE, StackOp::new(call_id, address, value));
         Ok(())
@@ -257,6 +258,7 @@ impl<'a> CircuitInputStateRef<'a> {
         address: StackAddress,
         value: Word,
     ) -> Result<(), Error> {
+        check_stack_address(address)?;
         let call_id = self.call()?.call_id;
         self.push_op(step, RW::READ, StackOp::new(call_id, address, value));
         Ok(())
@@ -1247,3 +1249,11 @@ impl<'a> CircuitInputStateRef<'a> {
         Ok(())
     }
 }
+
+/// Stack addresses range over the 1024 slots of the stack, `[0, 1024)`.
+fn check_stack_address(address: StackAddress) -> Result<(), Error> {
+    if address.0 >= 1024 {
+        return Err(eth_types::Error::InvalidStackPointer.into());
+    }
+    Ok(())
+}
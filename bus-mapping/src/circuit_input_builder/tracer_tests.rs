@@ -7,7 +7,7 @@ use crate::geth_errors::{
 };
 use crate::operation::RWCounter;
 use crate::state_db::Account;
-use eth_types::evm_types::{stack::Stack, Gas, OpcodeId};
+use eth_types::evm_types::{stack::Stack, Gas, OpcodeId, StackAddress};
 use eth_types::{
     address, bytecode, geth_types::GethData, word, Bytecode, Hash, ToAddress, ToWord, Word,
 };
@@ -122,6 +122,32 @@ fn check_err_depth(step: &GethExecStep, next_step: Option<&GethExecStep>) -> boo
         && step.depth == 1025
 }
 
+#[test]
+fn stack_op_address_out_of_range() {
+    let code = bytecode! {
+        PUSH1(0x1)
+        STOP
+    };
+    let block: GethData = TestContext::<2, 1>::simple_ctx_with_bytecode(code)
+        .unwrap()
+        .into();
+    let step = &block.geth_traces[0].struct_logs[0];
+    let mut builder = CircuitInputBuilderTx::new(&block, step);
+    let mut exec_step = builder.step.clone();
+
+    assert!(builder
+        .state_ref()
+        .stack_write(&mut exec_step, StackAddress::from(1023), Word::one())
+        .is_ok());
+    assert_eq!(
+        builder
+            .state_ref()
+            .stack_read(&mut exec_step, StackAddress::from(1024), Word::one()),
+        Err(Error::EthTypeError(eth_types::Error::InvalidStackPointer))
+    );
+    assert_eq!(exec_step.bus_mapping_instance.len(), 1);
+}
+
 #[test]
 fn tracer_err_depth() {
     // Recursive CALL will exaust the call depth
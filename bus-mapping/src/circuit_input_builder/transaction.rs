@@ -2,7 +2,7 @@
 
 use std::collections::BTreeMap;
 
-use eth_types::evm_types::Memory;
+use eth_types::evm_types::{gas_utils::effective_gas_price, HardFork, Memory};
 use eth_types::Signature;
 use eth_types::{geth_types, Address, GethExecTrace, Word};
 use ethers_core::utils::get_contract_address;
@@ -207,7 +207,9 @@ impl From<&Transaction> for geth_types::Transaction {
     fn from(tx: &Transaction) -> geth_types::Transaction {
         geth_types::Transaction {
             from: tx.from,
-            to: Some(tx.to),
+            // The callee of a creation is the created contract, which is not
+            // part of the signed transaction.
+            to: if tx.is_create() { None } else { Some(tx.to) },
             nonce: Word::from(tx.nonce),
             gas_limit: Word::from(tx.gas),
             value: tx.value,
@@ -242,12 +244,29 @@ impl Transaction {
         }
     }
 
+    /// Returns the price per unit of gas paid by `eth_tx` in a block with
+    /// `base_fee`, which for EIP-1559 transactions is the
+    /// [effective gas price](effective_gas_price).
+    pub fn effective_gas_price(eth_tx: &eth_types::Transaction, base_fee: Word) -> Word {
+        match (
+            eth_tx.transaction_type.map(|ty| ty.as_u64()),
+            eth_tx.max_fee_per_gas,
+            eth_tx.max_priority_fee_per_gas,
+        ) {
+            (Some(2), Some(max_fee_per_gas), Some(max_priority_fee_per_gas)) => {
+                effective_gas_price(max_fee_per_gas, max_priority_fee_per_gas, base_fee)
+            }
+            _ => eth_tx.gas_price.unwrap_or_default(),
+        }
+    }
+
     /// Create a new Self.
     pub fn new(
         call_id: usize,
         sdb: &StateDB,
         code_db: &mut CodeDB,
         eth_tx: &eth_types::Transaction,
+        base_fee: Word,
         is_success: bool,
     ) -> Result<Self, Error> {
         let (found, _) = sdb.get_account(&eth_tx.from);
@@ -300,7 +319,7 @@ impl Transaction {
         Ok(Self {
             nonce: eth_tx.nonce.as_u64(),
             gas: eth_tx.gas.as_u64(),
-            gas_price: eth_tx.gas_price.unwrap_or_default(),
+            gas_price: Self::effective_gas_price(eth_tx, base_fee),
             from: eth_tx.from,
             to: eth_tx
                 .to
@@ -317,6 +336,15 @@ impl Transaction {
         })
     }
 
+    /// Returns the intrinsic gas paid for the call data of this
+    /// [`Transaction`] under the gas schedule of `fork`.
+    pub fn call_data_gas_cost(&self, fork: HardFork) -> u64 {
+        self.input
+            .iter()
+            .map(|byte| fork.call_data_byte_gas_cost(*byte).as_u64())
+            .sum()
+    }
+
     /// Whether this [`Transaction`] is a create one
     pub fn is_create(&self) -> bool {
         self.calls[0].is_create()
@@ -362,3 +390,22 @@ impl Transaction {
         self.steps.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_data_gas_cost() {
+        let tx = Transaction {
+            input: vec![0, 1, 0, 0, 0xff],
+            ..Transaction::dummy()
+        };
+        assert_eq!(tx.call_data_gas_cost(HardFork::Petersburg), 3 * 4 + 2 * 68);
+        assert_eq!(tx.call_data_gas_cost(HardFork::Istanbul), 3 * 4 + 2 * 16);
+        assert_eq!(
+            Transaction::dummy().call_data_gas_cost(HardFork::default()),
+            0
+        );
+    }
+}
@@ -6,8 +6,8 @@ use ethers_providers::ProviderError;
 use std::error::Error as StdError;
 
 use crate::geth_errors::{
-    GETH_ERR_GAS_UINT_OVERFLOW, GETH_ERR_OUT_OF_GAS, GETH_ERR_STACK_OVERFLOW,
-    GETH_ERR_STACK_UNDERFLOW,
+    GETH_ERR_GAS_UINT_OVERFLOW, GETH_ERR_INVALID_JUMP, GETH_ERR_OUT_OF_GAS,
+    GETH_ERR_STACK_OVERFLOW, GETH_ERR_STACK_UNDERFLOW,
 };
 
 /// Error type for any BusMapping related failure.
@@ -15,6 +15,8 @@ use crate::geth_errors::{
 pub enum Error {
     /// Serde de/serialization error.
     SerdeError(serde_json::error::Error),
+    /// IO error, e.g. while reading a trace file.
+    IoError(String),
     /// JSON-RPC related error.
     JSONRpcError(ProviderError),
     /// OpcodeId is not a call type.
@@ -48,12 +50,57 @@ impl From<eth_types::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::SerdeError(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::IoError(err.to_string())
+    }
+}
+
 impl From<ProviderError> for Error {
     fn from(err: ProviderError) -> Self {
         Error::JSONRpcError(err)
     }
 }
 
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Serde and provider errors aren't comparable, so compare their
+            // messages.
+            (Error::SerdeError(a), Error::SerdeError(b)) => a.to_string() == b.to_string(),
+            (Error::JSONRpcError(a), Error::JSONRpcError(b)) => a.to_string() == b.to_string(),
+            (Error::IoError(a), Error::IoError(b)) => a == b,
+            (Error::AccountNotFound(a), Error::AccountNotFound(b))
+            | (Error::AddressNotFound(a), Error::AddressNotFound(b)) => a == b,
+            (Error::StorageKeyNotFound(a, a_key), Error::StorageKeyNotFound(b, b_key)) => {
+                a == b && a_key == b_key
+            }
+            (Error::CodeNotFound(a), Error::CodeNotFound(b)) => a == b,
+            (
+                Error::UnexpectedExecStepError(a, a_step),
+                Error::UnexpectedExecStepError(b, b_step),
+            )
+            | (Error::InvalidGethExecStep(a, a_step), Error::InvalidGethExecStep(b, b_step)) => {
+                a == b && a_step == b_step
+            }
+            (Error::InvalidGethExecTrace(a), Error::InvalidGethExecTrace(b))
+            | (Error::InternalError(a), Error::InternalError(b)) => a == b,
+            (Error::EthTypeError(a), Error::EthTypeError(b)) => a == b,
+            (Error::ExecutionError(a), Error::ExecutionError(b)) => a == b,
+            // The remaining variants carry no payload.
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Error {}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{:?}", self)
@@ -179,7 +226,63 @@ pub(crate) fn get_step_reported_error(op: &OpcodeId, error: &str) -> ExecError {
         ExecError::StackOverflow
     } else if error.starts_with(GETH_ERR_STACK_UNDERFLOW) {
         ExecError::StackUnderflow
+    } else if error == GETH_ERR_INVALID_JUMP {
+        ExecError::InvalidJump
     } else {
         panic!("Unknown GethExecStep.error: {}", error);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_trace_is_serde_error() {
+        let parse =
+            |input: &str| -> Result<Vec<GethExecStep>, Error> { Ok(serde_json::from_str(input)?) };
+        assert!(matches!(
+            parse(r#"[{"pc": 5, "op": "PUSH1""#),
+            Err(Error::SerdeError(_))
+        ));
+        assert!(matches!(parse("[]"), Ok(steps) if steps.is_empty()));
+    }
+
+    #[test]
+    fn step_reported_error() {
+        assert_eq!(
+            get_step_reported_error(&OpcodeId::ADD, GETH_ERR_OUT_OF_GAS),
+            ExecError::OutOfGas(OogError::Constant)
+        );
+        assert_eq!(
+            get_step_reported_error(&OpcodeId::MLOAD, GETH_ERR_OUT_OF_GAS),
+            ExecError::OutOfGas(OogError::StaticMemoryExpansion)
+        );
+        assert_eq!(
+            get_step_reported_error(&OpcodeId::JUMP, "invalid jump destination"),
+            ExecError::InvalidJump
+        );
+        assert_eq!(
+            get_step_reported_error(&OpcodeId::JUMPI, GETH_ERR_INVALID_JUMP),
+            ExecError::InvalidJump
+        );
+    }
+
+    #[test]
+    fn error_eq() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            OpcodeId::from_str("PUSH33").map_err(Error::from),
+            Err(Error::EthTypeError(eth_types::Error::OpcodeParsing(
+                "PUSH33".to_string()
+            )))
+        );
+        assert_ne!(
+            Error::ExecutionError(ExecError::InvalidJump),
+            Error::ExecutionError(ExecError::StackOverflow)
+        );
+        assert_eq!(Error::OpcodeIdNotCallType, Error::OpcodeIdNotCallType);
+        assert_ne!(Error::InternalError("a"), Error::InvalidGethExecTrace("a"));
+    }
+}
@@ -10,7 +10,9 @@ use crate::{
 };
 use core::fmt::Debug;
 use eth_types::{
-    evm_types::{GasCost, MAX_REFUND_QUOTIENT_OF_GAS_USED},
+    evm_types::{
+        gas_utils::memory_dynamic_gas_cost, GasCost, HardFork, MAX_REFUND_QUOTIENT_OF_GAS_USED,
+    },
     GethExecStep, ToAddress, ToWord, Word,
 };
 use keccak256::EMPTY_HASH;
@@ -55,6 +57,7 @@ mod swap;
 
 mod error_invalid_jump;
 mod error_oog_call;
+mod error_return_data_oob;
 
 #[cfg(test)]
 mod memory_expansion_test;
@@ -74,6 +77,7 @@ use create::DummyCreate;
 use dup::Dup;
 use error_invalid_jump::ErrorInvalidJump;
 use error_oog_call::OOGCall;
+use error_return_data_oob::ErrorReturnDataOutOfBound;
 use exp::Exponentiation;
 use extcodecopy::Extcodecopy;
 use extcodehash::Extcodehash;
@@ -258,6 +262,7 @@ fn fn_gen_error_state_associated_ops(error: &ExecError) -> Option<FnGenAssociate
     match error {
         ExecError::InvalidJump => Some(ErrorInvalidJump::gen_associated_ops),
         ExecError::OutOfGas(OogError::Call) => Some(OOGCall::gen_associated_ops),
+        ExecError::ReturnDataOutOfBounds => Some(ErrorReturnDataOutOfBound::gen_associated_ops),
         // more future errors place here
         _ => {
             warn!("TODO: error state {:?} not implemented", error);
@@ -321,6 +326,28 @@ pub fn gen_associated_ops(
             return Ok(vec![exec_step]);
         }
     }
+    // Catch malformed traces early: the reported gas cost must match the
+    // constant gas cost of the opcode, if it doesn't have a dynamic portion.
+    if let Some(gas_cost) = geth_step.op.fixed_gas_cost() {
+        if geth_step.gas_cost != gas_cost {
+            return Err(Error::InvalidGethExecStep(
+                "gas cost doesn't match the constant gas cost of the opcode",
+                geth_step.clone(),
+            ));
+        }
+    }
+    // Likewise for memory accesses whose dynamic gas cost disagrees with the
+    // memory size tracked for the call, as the EVM circuit would reject them.
+    let memory_word_size = state.call_ctx()?.memory.word_size() as u64;
+    if let Some(dynamic_gas_cost) = memory_dynamic_gas_cost(geth_step, memory_word_size) {
+        let expected = geth_step.op.constant_gas_cost().as_u64() + dynamic_gas_cost;
+        if geth_step.gas_cost.as_u64() != expected {
+            return Err(Error::InvalidGethExecStep(
+                "gas cost doesn't match the memory expansion cost of the opcode",
+                geth_step.clone(),
+            ));
+        }
+    }
     // if no errors, continue as normal
     fn_gen_associated_ops(state, geth_steps)
 }
@@ -368,11 +395,7 @@ pub fn gen_begin_tx_ops(state: &mut CircuitInputStateRef) -> Result<ExecStep, Er
     }
 
     // Calculate intrinsic gas cost
-    let call_data_gas_cost = state
-        .tx
-        .input
-        .iter()
-        .fold(0, |acc, byte| acc + if *byte == 0 { 4 } else { 16 });
+    let call_data_gas_cost = state.tx.call_data_gas_cost(HardFork::default());
     let intrinsic_gas_cost = if state.tx.is_create() {
         GasCost::CREATION_TX.as_u64()
     } else {
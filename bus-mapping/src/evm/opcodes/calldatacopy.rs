@@ -583,5 +583,14 @@ mod calldatacopy_tests {
             assert_eq!(value, calldata.get(offset as usize + idx).unwrap_or(&0));
             assert!(!is_code);
         }
+
+        // bytes_left goes down from the total length to 1 across the read steps.
+        let length = copy_events[0].length();
+        assert_eq!(length, size as u64);
+        for (idx, step_index) in (0..size * 2).step_by(2).enumerate() {
+            let bytes_left = copy_events[0].bytes_left(step_index);
+            assert!((1..=length).contains(&bytes_left));
+            assert_eq!(bytes_left, length - idx as u64);
+        }
     }
 }
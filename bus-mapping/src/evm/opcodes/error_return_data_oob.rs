@@ -0,0 +1,55 @@
+use crate::circuit_input_builder::{CircuitInputStateRef, ExecStep};
+use crate::evm::{Opcode, OpcodeId};
+use crate::operation::CallContextField;
+use crate::Error;
+use eth_types::GethExecStep;
+
+/// Placeholder structure used to implement [`Opcode`] trait over it
+/// corresponding to a `OpcodeId::RETURNDATACOPY` which reads past the end of
+/// the last callee's return data.  Unlike `CALLDATACOPY`, which zero-fills
+/// out of range bytes, this halts the current call with
+/// [`ExecError::ReturnDataOutOfBounds`](crate::error::ExecError::ReturnDataOutOfBounds).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ErrorReturnDataOutOfBound;
+
+impl Opcode for ErrorReturnDataOutOfBound {
+    fn gen_associated_ops(
+        state: &mut CircuitInputStateRef,
+        geth_steps: &[GethExecStep],
+    ) -> Result<Vec<ExecStep>, Error> {
+        let geth_step = &geth_steps[0];
+        let mut exec_step = state.new_step(geth_step)?;
+        let next_step = if geth_steps.len() > 1 {
+            Some(&geth_steps[1])
+        } else {
+            None
+        };
+        exec_step.error = state.get_step_err(geth_step, next_step).unwrap();
+        // assert op code can only be RETURNDATACOPY
+        assert_eq!(geth_step.op, OpcodeId::RETURNDATACOPY);
+
+        for i in 0..3 {
+            state.stack_read(
+                &mut exec_step,
+                geth_step.stack.nth_last_filled(i),
+                geth_step.stack.nth_last(i)?,
+            )?;
+        }
+
+        // The range check is done against the length of the last callee's
+        // return data, no byte is read nor written.
+        let call_id = state.call()?.call_id;
+        let return_data_length = state.call_ctx()?.return_data.len();
+        state.call_context_read(
+            &mut exec_step,
+            call_id,
+            CallContextField::LastCalleeReturnDataLength,
+            return_data_length.into(),
+        );
+
+        // `IsSuccess` call context operation is added in gen_restore_context_ops
+        state.gen_restore_context_ops(&mut exec_step, geth_steps)?;
+        state.handle_return(geth_step)?;
+        Ok(vec![exec_step])
+    }
+}
@@ -1,8 +1,10 @@
 use super::Opcode;
-use crate::circuit_input_builder::{CircuitInputStateRef, ExecStep};
-use crate::operation::{TxAccessListAccountOp, RW};
+use crate::circuit_input_builder::{
+    CircuitInputStateRef, CopyDataType, CopyEvent, ExecStep, NumberOrHash,
+};
+use crate::operation::{AccountField, CallContextField, TxAccessListAccountOp, RW};
 use crate::Error;
-use eth_types::{GethExecStep, ToAddress};
+use eth_types::{Bytecode, GethExecStep, ToAddress, ToWord, Word, H256};
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct Extcodecopy;
@@ -16,14 +18,11 @@ impl Opcode for Extcodecopy {
         let exec_steps = vec![gen_extcodecopy_step(state, geth_step)?];
 
         // reconstruction
-        let address = geth_steps[0].stack.nth_last(0)?.to_address();
         let dest_offset = geth_steps[0].stack.nth_last(1)?.as_u64();
         let code_offset = geth_steps[0].stack.nth_last(2)?.as_u64();
         let length = geth_steps[0].stack.nth_last(3)?.as_u64();
 
-        let (exist, account) = state.sdb.get_account(&address);
-        assert!(exist, "target account does not exist");
-        let code = state.code(account.code_hash)?;
+        let (_, code) = external_code(state, geth_step)?;
 
         let call_ctx = state.call_ctx_mut()?;
         let memory = &mut call_ctx.memory;
@@ -45,11 +44,27 @@ impl Opcode for Extcodecopy {
             }
         }
 
-        // TODO: implement copy event
+        let copy_event = gen_copy_event(state, geth_step)?;
+        state.push_copy(copy_event);
         Ok(exec_steps)
     }
 }
 
+/// Code hash and code of the account whose address is on top of the stack.
+/// A non-existing account has no code, and its code hash is taken as zero.
+fn external_code(
+    state: &CircuitInputStateRef,
+    geth_step: &GethExecStep,
+) -> Result<(H256, Vec<u8>), Error> {
+    let address = geth_step.stack.nth_last(0)?.to_address();
+    let account = state.sdb.get_account(&address).1;
+    if account.is_empty() {
+        Ok((H256::zero(), Vec::new()))
+    } else {
+        Ok((account.code_hash, state.code(account.code_hash)?))
+    }
+}
+
 fn gen_extcodecopy_step(
     state: &mut CircuitInputStateRef,
     geth_step: &GethExecStep,
@@ -61,37 +76,122 @@ fn gen_extcodecopy_step(
     let offset = geth_step.stack.nth_last(2)?;
     let length = geth_step.stack.nth_last(3)?;
 
-    let is_warm = state
-        .sdb
-        .check_account_in_access_list(&address.to_address());
+    // stack reads
+    state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(0), address)?;
+    state.stack_read(
+        &mut exec_step,
+        geth_step.stack.nth_last_filled(1),
+        dest_offset,
+    )?;
+    state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(2), offset)?;
+    state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(3), length)?;
+
+    // Read transaction ID, rw_counter_end_of_reversion, and is_persistent from call
+    // context.
+    for (field, value) in [
+        (CallContextField::TxId, state.tx_ctx.id().to_word()),
+        (
+            CallContextField::RwCounterEndOfReversion,
+            state.call()?.rw_counter_end_of_reversion.to_word(),
+        ),
+        (
+            CallContextField::IsPersistent,
+            state.call()?.is_persistent.to_word(),
+        ),
+    ] {
+        state.call_context_read(&mut exec_step, state.call()?.call_id, field, value);
+    }
+
+    // Update transaction access list for account address.
+    let address = address.to_address();
+    let is_warm = state.sdb.check_account_in_access_list(&address);
     state.push_op_reversible(
         &mut exec_step,
         RW::WRITE,
         TxAccessListAccountOp {
             tx_id: state.tx_ctx.id(),
-            address: address.to_address(),
+            address,
             is_warm: true,
             is_warm_prev: is_warm,
         },
     )?;
 
-    // stack reads
-    state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(0), address)?;
-    state.stack_read(
+    // Read account code hash, or its non-existence.
+    let (code_hash, _) = external_code(state, geth_step)?;
+    let (field, value) = if code_hash.is_zero() {
+        (AccountField::NonExisting, Word::zero())
+    } else {
+        (AccountField::CodeHash, code_hash.to_word())
+    };
+    state.account_read(&mut exec_step, address, field, value, value)?;
+
+    Ok(exec_step)
+}
+
+fn gen_copy_steps(
+    state: &mut CircuitInputStateRef,
+    exec_step: &mut ExecStep,
+    src_addr: u64,
+    dst_addr: u64,
+    bytes_left: u64,
+    bytecode: &Bytecode,
+) -> Result<Vec<(u8, bool)>, Error> {
+    let mut steps = Vec::with_capacity(bytes_left as usize);
+    for idx in 0..bytes_left {
+        let addr = src_addr + idx;
+        let bytecode_element = bytecode.get(addr as usize).unwrap_or_default();
+        steps.push((bytecode_element.value, bytecode_element.is_code));
+        state.memory_write(exec_step, (dst_addr + idx).into(), bytecode_element.value)?;
+    }
+    Ok(steps)
+}
+
+fn gen_copy_event(
+    state: &mut CircuitInputStateRef,
+    geth_step: &GethExecStep,
+) -> Result<CopyEvent, Error> {
+    let rw_counter_start = state.block_ctx.rwc;
+
+    let dst_offset = geth_step.stack.nth_last(1)?.as_u64();
+    let code_offset = geth_step.stack.nth_last(2)?.as_u64();
+    let length = geth_step.stack.nth_last(3)?.as_u64();
+
+    let (code_hash, code) = external_code(state, geth_step)?;
+    let bytecode: Bytecode = code.into();
+    let src_addr_end = bytecode.to_vec().len() as u64;
+
+    let mut exec_step = state.new_step(geth_step)?;
+    let copy_steps = gen_copy_steps(
+        state,
         &mut exec_step,
-        geth_step.stack.nth_last_filled(1),
-        dest_offset,
+        code_offset,
+        dst_offset,
+        length,
+        &bytecode,
     )?;
-    state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(2), offset)?;
-    state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(3), length)?;
-    Ok(exec_step)
+
+    Ok(CopyEvent {
+        src_type: CopyDataType::Bytecode,
+        src_id: NumberOrHash::Hash(code_hash),
+        src_addr: code_offset,
+        src_addr_end,
+        dst_type: CopyDataType::Memory,
+        dst_id: NumberOrHash::Number(state.call()?.call_id),
+        dst_addr: dst_offset,
+        log_id: None,
+        rw_counter_start,
+        bytes: copy_steps,
+    })
 }
 
 #[cfg(test)]
 mod extcodecopy_tests {
+    use crate::circuit_input_builder::{CopyDataType, ExecState, NumberOrHash};
     use crate::mock::BlockData;
+    use eth_types::evm_types::OpcodeId;
     use eth_types::geth_types::GethData;
-    use eth_types::{bytecode, word};
+    use eth_types::{bytecode, word, H256};
+    use ethers_core::utils::keccak256;
     use mock::test_ctx::helpers::{account_0_code_account_1_no_code, tx_from_1_to_0};
     use mock::TestContext;
 
@@ -150,4 +250,61 @@ mod extcodecopy_tests {
             .handle_block(&block.eth_block, &block.geth_traces)
             .unwrap();
     }
+
+    #[test]
+    fn test_copy_event() {
+        // Copy the own code past its end, so that the tail is zero-filled.
+        let code = bytecode! {
+            PUSH1(0x20)
+            PUSH1(0x4)
+            PUSH1(0x10)
+            ADDRESS
+            EXTCODECOPY
+            STOP
+        };
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(code.clone()),
+            tx_from_1_to_0,
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::EXTCODECOPY))
+            .unwrap();
+        let expected_call_id = builder.block.txs()[0].calls()[step.call_index].call_id;
+
+        let copy_events = builder.block.copy_events.clone();
+        assert_eq!(copy_events.len(), 1);
+        assert_eq!(copy_events[0].bytes.len(), 0x20);
+        assert_eq!(
+            copy_events[0].src_id,
+            NumberOrHash::Hash(H256(keccak256(&code.to_vec())))
+        );
+        assert_eq!(copy_events[0].src_addr, 0x4);
+        assert_eq!(copy_events[0].src_addr_end as usize, code.to_vec().len());
+        assert_eq!(copy_events[0].src_type, CopyDataType::Bytecode);
+        assert_eq!(
+            copy_events[0].dst_id,
+            NumberOrHash::Number(expected_call_id)
+        );
+        assert_eq!(copy_events[0].dst_addr, 0x10);
+        assert_eq!(copy_events[0].dst_type, CopyDataType::Memory);
+        assert!(copy_events[0].log_id.is_none());
+
+        for (idx, (value, is_code)) in copy_events[0].bytes.iter().enumerate() {
+            let bytecode_element = code.get(0x4 + idx).unwrap_or_default();
+            assert_eq!(*value, bytecode_element.value);
+            assert_eq!(*is_code, bytecode_element.is_code);
+        }
+    }
 }
@@ -85,7 +85,7 @@ mod mstore_tests {
     };
     use eth_types::{
         bytecode,
-        evm_types::{MemoryAddress, OpcodeId, StackAddress},
+        evm_types::{gas_utils::memory_dynamic_gas_cost, MemoryAddress, OpcodeId, StackAddress},
         geth_types::GethData,
         Word,
     };
@@ -159,6 +159,50 @@ mod mstore_tests {
         )
     }
 
+    #[test]
+    fn mstore_dynamic_gas_cost() {
+        let code = bytecode! {
+            PUSH2(0x1234)
+            PUSH2(0x100)
+            MSTORE
+            STOP
+        };
+
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(code),
+            tx_from_1_to_0,
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::MSTORE))
+            .unwrap();
+        assert_eq!(step.memory_size, 0);
+
+        // The memory expands from 0 to 9 words, costing 3 * 9 + 9 * 9 / 512.
+        let geth_step = block.geth_traces[0]
+            .struct_logs
+            .iter()
+            .find(|step| step.op == OpcodeId::MSTORE)
+            .unwrap();
+        let dynamic_gas_cost = memory_dynamic_gas_cost(geth_step, 0).unwrap();
+        assert_eq!(dynamic_gas_cost, 27);
+        assert_eq!(
+            geth_step.gas_cost.as_u64(),
+            OpcodeId::MSTORE.constant_gas_cost().as_u64() + dynamic_gas_cost
+        );
+    }
+
     #[test]
     fn mstore8_opcode_impl() {
         let code = bytecode! {
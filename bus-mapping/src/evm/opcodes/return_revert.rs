@@ -259,10 +259,11 @@ fn handle_create(
 #[cfg(test)]
 mod return_tests {
     use crate::mock::BlockData;
-    use eth_types::geth_types::GethData;
-    use eth_types::{bytecode, word};
+    use eth_types::geth_types::{self, GethData};
+    use eth_types::{bytecode, word, ToBigEndian, Word};
+    use ethers_core::utils::get_contract_address;
     use mock::test_ctx::helpers::{account_0_code_account_1_no_code, tx_from_1_to_0};
-    use mock::TestContext;
+    use mock::{eth, TestContext, MOCK_ACCOUNTS};
 
     #[test]
     fn test_ok() {
@@ -379,4 +380,48 @@ mod return_tests {
             .handle_block(&block.eth_block, &block.geth_traces)
             .unwrap();
     }
+
+    #[test]
+    fn test_create_tx() {
+        // The constructor of test_ok, deploying 0x6020600060003760206000F3.
+        let init_code = bytecode! {
+            PUSH12(word!("6020600060003760206000F3"))
+            PUSH1(0)
+            MSTORE
+            PUSH1 (0xC)
+            PUSH1 (0x14)
+            RETURN
+        };
+        let deployed_code = word!("6020600060003760206000F3").to_be_bytes()[20..].to_vec();
+
+        let block: GethData = TestContext::<1, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(MOCK_ACCOUNTS[0]).balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .input(init_code.to_vec().into());
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let tx = &builder.block.txs()[0];
+        let address = get_contract_address(MOCK_ACCOUNTS[0], Word::zero());
+        assert!(tx.is_create());
+        assert_eq!(tx.to, address);
+        assert!(geth_types::Transaction::from(tx).is_create());
+
+        let (found, account) = builder.sdb.get_account(&address);
+        assert!(found);
+        assert_eq!(builder.code_db.0[&account.code_hash], deployed_code);
+    }
 }
@@ -35,14 +35,11 @@ impl Opcode for Returndatacopy {
             let data_starts = offset.as_usize();
             let data_ends = data_starts + length;
             let minimal_length = dest_offset.as_usize() + length;
-            if data_ends <= return_data.len() {
-                memory.extend_at_least(minimal_length);
-                memory[mem_starts..mem_ends].copy_from_slice(&return_data[data_starts..data_ends]);
-            } else {
-                assert_eq!(geth_steps.len(), 1);
-                // if overflows this opcode would fails current context, so
-                // there is no more steps.
-            }
+            // Reading past the end of the return data is handled by the
+            // `ReturnDataOutOfBounds` error state, so the range is valid here.
+            debug_assert!(data_ends <= return_data.len());
+            memory.extend_at_least(minimal_length);
+            memory[mem_starts..mem_ends].copy_from_slice(&return_data[data_starts..data_ends]);
         }
 
         let copy_event = gen_copy_event(state, geth_step)?;
@@ -181,7 +178,10 @@ fn gen_copy_event(
 
 #[cfg(test)]
 mod return_tests {
+    use crate::circuit_input_builder::{ExecState, ExecStep};
+    use crate::error::ExecError;
     use crate::mock::BlockData;
+    use eth_types::evm_types::OpcodeId;
     use eth_types::geth_types::GethData;
     use eth_types::{bytecode, word};
     use mock::test_ctx::helpers::{account_0_code_account_1_no_code, tx_from_1_to_0};
@@ -249,10 +249,15 @@ mod return_tests {
         builder
             .handle_block(&block.eth_block, &block.geth_traces)
             .unwrap();
+
+        let step = returndatacopy_step(builder.block.txs()[0].steps());
+        assert_eq!(step.error, None);
+        // 3 stack reads + 3 call context reads.
+        assert_eq!(step.bus_mapping_instance.len(), 6);
     }
 
     #[test]
-    fn test_revert() {
+    fn test_out_of_bound() {
         // // deployed contract
         // PUSH1 0x20
         // PUSH1 0
@@ -313,5 +318,20 @@ mod return_tests {
         builder
             .handle_block(&block.eth_block, &block.geth_traces)
             .unwrap();
+
+        // Unlike CALLDATACOPY, reading past the return data is a fault rather
+        // than a zero-filled copy.
+        let step = returndatacopy_step(builder.block.txs()[0].steps());
+        assert_eq!(step.error, Some(ExecError::ReturnDataOutOfBounds));
+        // 3 stack reads + return data length read + `IsSuccess` read of the
+        // failed root call, no memory is touched.
+        assert_eq!(step.bus_mapping_instance.len(), 5);
+    }
+
+    fn returndatacopy_step(steps: &[ExecStep]) -> &ExecStep {
+        steps
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::RETURNDATACOPY))
+            .unwrap()
     }
 }
@@ -81,16 +81,23 @@ impl Opcode for Sha3 {
 
 #[cfg(any(feature = "test", test))]
 pub mod sha3_tests {
-    use eth_types::{bytecode, evm_types::OpcodeId, geth_types::GethData, Bytecode, Word};
+    use eth_types::{
+        bytecode,
+        evm_types::OpcodeId,
+        geth_types::{self, GethData},
+        Bytecode, Word,
+    };
     use ethers_core::utils::keccak256;
+    use itertools::Itertools;
     use mock::{
+        eth,
         test_ctx::helpers::{account_0_code_account_1_no_code, tx_from_1_to_0},
-        TestContext,
+        AddrOrWallet, TestContext, MOCK_ACCOUNTS, MOCK_WALLETS,
     };
     use rand::{random, Rng};
 
     use crate::{
-        circuit_input_builder::{CircuitsParams, ExecState},
+        circuit_input_builder::{keccak_inputs, CircuitsParams, ExecState},
         mock::BlockData,
         operation::{MemoryOp, StackOp, RW},
     };
@@ -262,6 +269,44 @@ pub mod sha3_tests {
         }
     }
 
+    #[test]
+    fn sha3_keccak_inputs() {
+        let (code, memory) = gen_sha3_code(0x20, 0x40, MemoryKind::EqualToSize);
+        let wallet = AddrOrWallet::from(MOCK_WALLETS[0].clone());
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(eth(10))
+                    .code(code);
+                accs[1].address(wallet.address()).balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0].from(wallet.clone()).to(accs[0].address);
+            },
+            |block, _txs| block,
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let inputs = keccak_inputs(&builder.block, &builder.code_db).unwrap();
+
+        // The SHA3 operand.
+        assert!(inputs.contains(&memory[0x20..0x60].to_vec()));
+        // The tx RLP that is signed.
+        let tx = geth_types::Transaction::from(&block.eth_block.transactions[0]);
+        let sign_msg = tx.sign_msg(block.chain_id.as_u64()).unwrap();
+        assert!(inputs.contains(&sign_msg));
+        // Preimages are deduplicated.
+        assert_eq!(inputs.iter().unique().count(), inputs.len());
+    }
+
     #[test]
     fn sha3_opcode_ok() {
         test_ok(0x10, 0x32, MemoryKind::Empty);
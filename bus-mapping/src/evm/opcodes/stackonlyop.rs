@@ -19,6 +19,8 @@ impl<const N_POP: usize, const N_PUSH: usize> Opcode for StackOnlyOpcode<N_POP,
     ) -> Result<Vec<ExecStep>, Error> {
         let geth_step = &geth_steps[0];
         let mut exec_step = state.new_step(geth_step)?;
+        debug_assert_eq!(N_POP, geth_step.op.stack_pops());
+        debug_assert_eq!(N_PUSH, geth_step.op.stack_pushes());
         // N_POP stack reads
         for i in 0..N_POP {
             state.stack_read(
@@ -1,7 +1,12 @@
 //! This module contains the logic for parsing and interacting with EVM
 //! execution traces.
 use crate::operation::Target;
+use crate::Error;
+use eth_types::GethExecTrace;
 use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// The target and index of an `Operation` in the context of an
@@ -48,3 +53,76 @@ impl OperationRef {
         self.0
     }
 }
+
+/// Loads a [`GethExecTrace`], as returned by geth's `debug_trace*` methods,
+/// from a JSON file.  Failing to read the file results in an
+/// [`Error::IoError`], while a malformed trace results in an
+/// [`Error::SerdeError`].
+pub fn geth_exec_trace_from_file(path: impl AsRef<Path>) -> Result<GethExecTrace, Error> {
+    geth_exec_trace_from_reader(BufReader::new(File::open(path)?))
+}
+
+/// Parses a [`GethExecTrace`] from a JSON stream, without holding the whole
+/// JSON in memory.  A malformed trace results in an [`Error::SerdeError`].
+pub fn geth_exec_trace_from_reader<R: Read>(reader: R) -> Result<GethExecTrace, Error> {
+    Ok(serde_json::from_reader(reader)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eth_types::evm_types::OpcodeId;
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn trace_from_file() {
+        let trace = geth_exec_trace_from_file(fixture("geth_exec_trace.json")).unwrap();
+        assert!(!trace.failed);
+        assert_eq!(
+            trace
+                .struct_logs
+                .iter()
+                .map(|step| step.op)
+                .collect::<Vec<_>>(),
+            vec![OpcodeId::PUSH1, OpcodeId::POP, OpcodeId::STOP]
+        );
+    }
+
+    #[test]
+    fn trace_from_reader() {
+        let json = std::fs::read(fixture("geth_exec_trace.json")).unwrap();
+        let trace = geth_exec_trace_from_reader(std::io::Cursor::new(json)).unwrap();
+        assert_eq!(
+            trace,
+            geth_exec_trace_from_file(fixture("geth_exec_trace.json")).unwrap()
+        );
+        assert_eq!(trace.struct_logs.len(), 3);
+
+        assert!(matches!(
+            geth_exec_trace_from_reader(std::io::Cursor::new(b"{\"gas\": 1".to_vec())),
+            Err(Error::SerdeError(_))
+        ));
+    }
+
+    #[test]
+    fn trace_from_missing_file() {
+        assert!(matches!(
+            geth_exec_trace_from_file(fixture("missing.json")),
+            Err(Error::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn trace_from_malformed_file() {
+        // The file exists but doesn't hold a trace.
+        assert!(matches!(
+            geth_exec_trace_from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")),
+            Err(Error::SerdeError(_))
+        ));
+    }
+}
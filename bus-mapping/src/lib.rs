@@ -57,6 +57,7 @@
 //! use eth_types::evm_types::Gas;
 //! use bus_mapping::circuit_input_builder::{Block, CircuitInputBuilder};
 //!
+//! # fn main() -> Result<(), Error> {
 //! let input_trace = r#"
 //! [
 //!     {
@@ -136,7 +137,7 @@
 //!     .handle_block(&block.eth_block, &block.geth_traces)
 //!     .unwrap();
 //!
-//! let geth_steps: Vec<GethExecStep> = serde_json::from_str(input_trace).unwrap();
+//! let geth_steps: Vec<GethExecStep> = serde_json::from_str(input_trace)?;
 //! let geth_trace = GethExecTrace {
 //!     return_value: "".to_string(),
 //!     gas: Gas(block.eth_block.transactions[0].gas.as_u64()),
@@ -149,6 +150,8 @@
 //!
 //! // You can also iterate over the steps of the trace and witness the EVM Proof.
 //! builder.block.txs()[0].steps().iter();
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! Assume we have the following trace:
@@ -133,7 +133,8 @@ pub struct MemoryOp {
     pub call_id: usize,
     /// Memory Address
     pub address: MemoryAddress,
-    /// Value
+    /// Value, a single byte.  The State Circuit range checks it with the
+    /// "memory value is a byte" lookup.
     pub value: u8,
 }
 
@@ -290,11 +291,14 @@ pub struct StorageOp {
     pub key: Word,
     /// Storage Value after the operation
     pub value: Word,
-    /// Storage Value before the operation
+    /// Storage Value before the operation, which is the "current" value of
+    /// EIP-2200.
     pub value_prev: Word,
     /// Transaction ID: Transaction index in the block starting at 1.
     pub tx_id: usize,
-    /// Storage Value before the transaction
+    /// Storage Value before the transaction, which is the "original" value of
+    /// EIP-2200.  It differs from `value_prev` when the slot has already been
+    /// written in the same transaction.
     pub committed_value: Word,
 }
 
@@ -310,7 +314,9 @@ impl fmt::Debug for StorageOp {
 }
 
 impl StorageOp {
-    /// Create a new instance of a `StorageOp` from it's components.
+    /// Create a new instance of a `StorageOp` from it's components: the
+    /// `value` after the operation, the `value_prev` before the operation and
+    /// the `committed_value` before the transaction `tx_id`.
     pub const fn new(
         address: Address,
         key: Word,
@@ -353,6 +359,12 @@ impl StorageOp {
     pub const fn value_prev(&self) -> &Word {
         &self.value_prev
     }
+
+    /// Returns the [`Word`] at key found previous to the transaction of this
+    /// operation.
+    pub const fn committed_value(&self) -> &Word {
+        &self.committed_value
+    }
 }
 
 impl Op for StorageOp {
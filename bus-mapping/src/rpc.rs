@@ -7,8 +7,14 @@ use eth_types::{
     Transaction, Word, U64,
 };
 pub use ethers_core::types::BlockNumber;
-use ethers_providers::JsonRpcClient;
+use ethers_providers::{HttpClientError, JsonRpcClient, ProviderError};
+use log::warn;
 use serde::Serialize;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
 
 /// Serialize a type.
 ///
@@ -47,6 +53,102 @@ impl Default for GethLoggerConfig {
     }
 }
 
+/// Retry policy of JSON-RPC calls, so that transient failures of the node
+/// don't fail a whole block.  A call failing with a transient error is
+/// retried after `base_delay`, doubling the delay at each further retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Number of times a failing call is retried before giving up
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Call `f` until the future it returns doesn't fail with a transient
+    /// [`Error::JSONRpcError`], or `max_retries` retries failed.  The backoff
+    /// delays don't rely on the timer of any async runtime.
+    pub async fn retry<T, Fut>(&self, mut f: impl FnMut() -> Fut) -> Result<T, Error>
+    where
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut delay = self.base_delay;
+        let mut retries = 0;
+        loop {
+            match f().await {
+                Err(Error::JSONRpcError(err))
+                    if retries < self.max_retries && is_transient(&err) =>
+                {
+                    warn!("JSON-RPC call failed, retrying in {:?}: {}", delay, err);
+                    Delay::new(delay).await;
+                    delay *= 2;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether a failed JSON-RPC call may succeed when retried, which is the case
+/// when the transport failed, e.g. the connection dropped or timed out.  An
+/// error returned by the node, or a response that can't be decoded, is not
+/// transient.  The errors of transports other than HTTP are all assumed to be
+/// transient.
+fn is_transient(err: &ProviderError) -> bool {
+    match err {
+        ProviderError::JsonRpcClientError(err) => match err.downcast_ref::<HttpClientError>() {
+            Some(HttpClientError::ReqwestError(err)) => err.is_timeout() || err.is_connect(),
+            Some(_) => false,
+            None => true,
+        },
+        _ => false,
+    }
+}
+
+/// Future completing once a duration elapsed, timed by a helper thread.  It
+/// holds whether the duration elapsed and the waker of the task awaiting it.
+struct Delay(Arc<Mutex<(bool, Option<Waker>)>>);
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let thread_state = state.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            let mut state = thread_state.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+        Self(state)
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.0.lock().unwrap();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
 /// Placeholder structure designed to contain the methods that the BusMapping
 /// needs in order to enable Geth queries.
 pub struct GethClient<P: JsonRpcClient>(pub P);
@@ -186,3 +288,73 @@ impl<P: JsonRpcClient> GethClient<P> {
 }
 
 // Integration tests found in `integration-tests/tests/rpc.rs`.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_providers::MockProvider;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn retry_with_backoff() {
+        // The mock provider fails the calls made while it has no response.
+        let provider = MockProvider::new();
+        let client = GethClient::new(provider.clone());
+        let retry = RetryConfig {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+        };
+        let block_num = BlockNumber::Number(1u64.into());
+
+        let attempts = Cell::new(0);
+        let traces = retry
+            .retry(|| {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() == 3 {
+                    provider
+                        .push::<serde_json::Value, _>(serde_json::json!([]))
+                        .unwrap();
+                }
+                client.trace_block_by_number(block_num)
+            })
+            .await;
+        assert_eq!(attempts.get(), 3);
+        assert!(traces.unwrap().is_empty());
+
+        let retry = RetryConfig {
+            max_retries: 1,
+            ..retry
+        };
+        attempts.set(0);
+        let traces = retry
+            .retry(|| {
+                attempts.set(attempts.get() + 1);
+                client.trace_block_by_number(block_num)
+            })
+            .await;
+        assert_eq!(attempts.get(), 2);
+        assert!(matches!(traces, Err(Error::JSONRpcError(_))));
+    }
+
+    #[tokio::test]
+    async fn retry_only_transient_errors() {
+        let retry = RetryConfig {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+        };
+
+        let attempts = Cell::new(0);
+        let result: Result<(), _> = retry
+            .retry(|| {
+                attempts.set(attempts.get() + 1);
+                async {
+                    Err(Error::JSONRpcError(ProviderError::CustomError(
+                        "not transient".to_string(),
+                    )))
+                }
+            })
+            .await;
+        assert_eq!(attempts.get(), 1);
+        assert!(matches!(result, Err(Error::JSONRpcError(_))));
+    }
+}
@@ -1,7 +1,10 @@
 //! EVM byte code generator
 
 use crate::{evm_types::OpcodeId, Bytes, Word};
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 /// Error type for Bytecode related failures
 #[derive(Debug)]
@@ -209,6 +212,22 @@ impl Bytecode {
     pub fn iter(&self) -> BytecodeIterator<'_> {
         BytecodeIterator(self.code.iter())
     }
+
+    /// Positions of the valid jump destinations, i.e. of the JUMPDEST
+    /// opcodes, skipping the push data bytes even when they hold a JUMPDEST
+    /// byte.
+    pub fn jumpdests(&self) -> HashSet<usize> {
+        let mut jumpdests = HashSet::new();
+        let mut pos = 0;
+        while let Some(byte) = self.code.get(pos) {
+            let op = OpcodeId::from(byte.value);
+            if op == OpcodeId::JUMPDEST {
+                jumpdests.insert(pos);
+            }
+            pos += 1 + op.push_data_len();
+        }
+        jumpdests
+    }
 }
 
 /// An ASM entry
@@ -274,7 +293,7 @@ impl<'a> Iterator for BytecodeIterator<'a> {
         self.0.next().map(|byte| {
             let op = OpcodeId::from(byte.value);
             if op.is_push() {
-                let n = op.data_len();
+                let n = op.push_data_len();
                 let mut value = vec![0u8; n];
                 for value_byte in value.iter_mut() {
                     *value_byte = self.0.next().unwrap().value;
@@ -17,13 +17,18 @@ pub enum Error {
     /// Error while parsing an `Instruction/Opcode`.
     OpcodeParsing(String),
     /// Error while parsing a `MemoryAddress`.
-    MemAddressParsing,
+    MemAddressParsing(String),
     /// Error while parsing a `StackAddress`.
-    StackAddressParsing,
+    StackAddressParsing(String),
+    /// Error while parsing an EVM `Word`.
+    EvmWordParsing(String),
     /// Error while trying to convert to an incorrect `OpcodeId`.
     InvalidOpConversion,
     /// Error while trying to access an invalid/empty Stack location.
     InvalidStackPointer,
+    /// Error while trying to access a Stack location deeper than the stack
+    /// size, counting from the top of the stack.
+    StackUnderflow(usize),
     /// Error while trying to access an invalid/empty Memory location.
     InvalidMemoryPointer,
     /// Error while trying to access an invalid/empty Storage key.
@@ -31,6 +36,11 @@ pub enum Error {
     /// Error when an EvmWord is too big to be converted into a
     /// `MemoryAddress`.
     WordToMemAddr,
+    /// Error while trying to access a bit of an EvmWord past the 256th one.
+    BitIndexOutOfRange(usize),
+    /// Error when a jump of a trace, at the given program counter, lands on
+    /// a destination which isn't a JUMPDEST of the code.
+    InvalidJumpDestination(usize, crate::Word),
     /// Signature parsing error.
     Signature(libsecp256k1::Error),
 }
@@ -41,9 +51,44 @@ impl From<libsecp256k1::Error> for Error {
     }
 }
 
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // `serde_json::Error` isn't comparable, so compare its message.
+            (Error::SerdeError(a), Error::SerdeError(b)) => a.to_string() == b.to_string(),
+            (Error::TracingError(a), Error::TracingError(b))
+            | (Error::OpcodeParsing(a), Error::OpcodeParsing(b))
+            | (Error::MemAddressParsing(a), Error::MemAddressParsing(b))
+            | (Error::StackAddressParsing(a), Error::StackAddressParsing(b))
+            | (Error::EvmWordParsing(a), Error::EvmWordParsing(b)) => a == b,
+            (Error::InvalidOpcodeIdByte(a), Error::InvalidOpcodeIdByte(b)) => a == b,
+            (Error::StackUnderflow(a), Error::StackUnderflow(b))
+            | (Error::BitIndexOutOfRange(a), Error::BitIndexOutOfRange(b)) => a == b,
+            (Error::InvalidJumpDestination(a, c), Error::InvalidJumpDestination(b, d)) => {
+                a == b && c == d
+            }
+            (Error::Signature(a), Error::Signature(b)) => a == b,
+            // The remaining variants carry no payload.
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Error {}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:?}", self)
+        match self {
+            Error::OpcodeParsing(s) => write!(f, "OpcodeParsing: invalid opcode {:?}", s),
+            Error::MemAddressParsing(s) => {
+                write!(f, "MemAddressParsing: invalid memory address {:?}", s)
+            }
+            Error::StackAddressParsing(s) => {
+                write!(f, "StackAddressParsing: invalid stack address {:?}", s)
+            }
+            Error::EvmWordParsing(s) => write!(f, "EvmWordParsing: invalid word {:?}", s),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
@@ -57,3 +102,26 @@ pub enum EthAddressParsingError {
     /// Hex decoding error
     Hex(hex::FromHexError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_eq() {
+        assert_eq!(
+            Error::OpcodeParsing("PUSH0".to_string()),
+            Error::OpcodeParsing("PUSH0".to_string())
+        );
+        assert_ne!(
+            Error::OpcodeParsing("PUSH0".to_string()),
+            Error::OpcodeParsing("PUSH33".to_string())
+        );
+        assert_ne!(
+            Error::OpcodeParsing("0x10".to_string()),
+            Error::MemAddressParsing("0x10".to_string())
+        );
+        assert_eq!(Error::InvalidStackPointer, Error::InvalidStackPointer);
+        assert_ne!(Error::InvalidStackPointer, Error::InvalidMemoryPointer);
+    }
+}
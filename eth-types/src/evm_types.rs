@@ -67,6 +67,29 @@ pub const MAX_REFUND_QUOTIENT_OF_GAS_USED: usize = 5;
 /// Gas stipend when CALL or CALLCODE is attached with value.
 pub const GAS_STIPEND_CALL_WITH_VALUE: u64 = 2300;
 
+/// Hard forks of the Ethereum mainnet that changed a gas cost which depends
+/// on the fork here.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub enum HardFork {
+    /// Any fork before Istanbul.
+    Petersburg,
+    /// Istanbul, and the later forks which kept its call data gas cost.
+    #[default]
+    Istanbul,
+}
+
+impl HardFork {
+    /// Returns the gas cost of a byte of transaction call data. EIP-2028
+    /// reduced the cost of a non-zero byte from 68 to 16 in Istanbul.
+    pub fn call_data_byte_gas_cost(&self, byte: u8) -> GasCost {
+        match (byte, self) {
+            (0, _) => GasCost::CALL_DATA_ZERO_BYTE,
+            (_, Self::Petersburg) => GasCost::CALL_DATA_NON_ZERO_BYTE_PETERSBURG,
+            (_, Self::Istanbul) => GasCost::CALL_DATA_NON_ZERO_BYTE,
+        }
+    }
+}
+
 /// Defines the gas consumption.
 #[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct GasCost(pub u64);
@@ -122,6 +145,13 @@ impl GasCost {
     pub const TX: Self = Self(21000);
     /// Constant cost for a creation transaction
     pub const CREATION_TX: Self = Self(53000);
+    /// Constant cost for a zero byte of transaction call data
+    pub const CALL_DATA_ZERO_BYTE: Self = Self(4);
+    /// Constant cost for a non-zero byte of transaction call data
+    pub const CALL_DATA_NON_ZERO_BYTE: Self = Self(16);
+    /// Constant cost for a non-zero byte of transaction call data before
+    /// EIP-2028
+    pub const CALL_DATA_NON_ZERO_BYTE_PETERSBURG: Self = Self(68);
     /// Constant cost for calling with non-zero value
     pub const CALL_WITH_VALUE: Self = Self(9000);
     /// Constant cost for turning empty account into non-empty account
@@ -1,7 +1,7 @@
 //! Utility functions to help calculate gas
 
-use super::GasCost;
-use crate::Word;
+use super::{GasCost, OpcodeId};
+use crate::{GethExecStep, Word};
 
 /// Calculate memory expansion gas cost by current and next memory word size.
 pub fn memory_expansion_gas_cost(curr_memory_word_size: u64, next_memory_word_size: u64) -> u64 {
@@ -28,6 +28,33 @@ pub fn memory_copier_gas_cost(
         + memory_expansion_gas_cost(curr_memory_word_size, next_memory_word_size)
 }
 
+/// Recompute the dynamic portion of the gas cost of a successful MLOAD, MSTORE,
+/// MSTORE8 or SHA3 step from its stack and the memory word size before it: the
+/// memory expansion cost, plus the per word cost of SHA3.  Returns `None` for
+/// the other opcodes.
+pub fn memory_dynamic_gas_cost(step: &GethExecStep, curr_memory_word_size: u64) -> Option<u64> {
+    let (offset, length, copy_word_cost) = match step.op {
+        OpcodeId::MLOAD | OpcodeId::MSTORE => (step.stack.last().ok()?, 32, 0),
+        OpcodeId::MSTORE8 => (step.stack.last().ok()?, 1, 0),
+        OpcodeId::SHA3 => (
+            step.stack.last().ok()?,
+            step.stack.nth_last(1).ok()?.as_u64(),
+            GasCost::COPY_SHA3.as_u64(),
+        ),
+        _ => return None,
+    };
+    // A zero length access doesn't expand the memory, whatever its offset.
+    let next_memory_word_size = if length == 0 {
+        curr_memory_word_size
+    } else {
+        curr_memory_word_size.max((offset.as_u64() + length + 31) / 32)
+    };
+    Some(
+        (length + 31) / 32 * copy_word_cost
+            + memory_expansion_gas_cost(curr_memory_word_size, next_memory_word_size),
+    )
+}
+
 /// Calculate EIP 150 gas passed to callee.
 pub fn eip150_gas(gas_left: u64, gas_specified: Word) -> u64 {
     let capped_gas = gas_left - gas_left / 64;
@@ -41,3 +68,69 @@ pub fn eip150_gas(gas_left: u64, gas_specified: Word) -> u64 {
 
     capped_gas
 }
+
+/// Calculate the effective gas price of an EIP-1559 transaction, which is the
+/// base fee plus the priority fee, capped by the max fee.
+pub fn effective_gas_price(
+    max_fee_per_gas: Word,
+    max_priority_fee_per_gas: Word,
+    base_fee: Word,
+) -> Word {
+    max_fee_per_gas.min(base_fee.saturating_add(max_priority_fee_per_gas))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm_types::{Gas, Memory, ProgramCounter, Stack, Storage};
+
+    #[test]
+    fn effective_gas_price_priority_fee_not_capped() {
+        // base fee + priority fee = 110 <= 200
+        assert_eq!(
+            effective_gas_price(Word::from(200), Word::from(10), Word::from(100)),
+            Word::from(110)
+        );
+    }
+
+    #[test]
+    fn effective_gas_price_priority_fee_capped() {
+        // base fee + priority fee = 150 > 120
+        assert_eq!(
+            effective_gas_price(Word::from(120), Word::from(50), Word::from(100)),
+            Word::from(120)
+        );
+    }
+
+    #[test]
+    fn memory_dynamic_gas_cost_sha3() {
+        let step = GethExecStep {
+            pc: ProgramCounter(0),
+            op: OpcodeId::SHA3,
+            gas: Gas(1000),
+            gas_cost: GasCost(0),
+            refund: Gas(0),
+            depth: 1,
+            error: None,
+            // size 0x21 at offset 0x10
+            stack: Stack(vec![Word::from(0x21), Word::from(0x10)]),
+            memory: Memory::new(),
+            storage: Storage::empty(),
+        };
+        // 2 words are hashed and the memory expands from 1 to 2 words.
+        assert_eq!(memory_dynamic_gas_cost(&step, 1), Some(2 * 6 + 3));
+        assert_eq!(memory_dynamic_gas_cost(&step, 2), Some(2 * 6));
+
+        let empty = GethExecStep {
+            stack: Stack(vec![Word::zero(), Word::MAX]),
+            ..step.clone()
+        };
+        assert_eq!(memory_dynamic_gas_cost(&empty, 0), Some(0));
+
+        let add = GethExecStep {
+            op: OpcodeId::ADD,
+            ..step
+        };
+        assert_eq!(memory_dynamic_gas_cost(&add, 0), None);
+    }
+}
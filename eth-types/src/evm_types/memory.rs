@@ -90,7 +90,7 @@ impl FromStr for MemoryAddress {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(MemoryAddress(
-            usize::from_str_radix(s, 16).map_err(|_| Error::MemAddressParsing)?,
+            usize::from_str_radix(s, 16).map_err(|_| Error::MemAddressParsing(s.to_string()))?,
         ))
     }
 }
@@ -319,6 +319,13 @@ impl Memory {
         self.0.len() / 32
     }
 
+    /// Returns the size of memory in bytes, which is the highest written
+    /// address rounded up to the next multiple of 32, as tracked by the EVM
+    /// circuit.
+    pub fn memory_size(&self) -> u64 {
+        ((self.0.len() + 31) / 32 * 32) as u64
+    }
+
     /// Resize the memory for at least length and align to 32 bytes.
     pub fn extend_at_least(&mut self, minimal_length: usize) {
         let memory_size = (minimal_length + 31) / 32 * 32;
@@ -344,6 +351,33 @@ mod memory_tests {
         Ok(())
     }
 
+    #[test]
+    fn mem_addr_parsing_error() {
+        let err = MemoryAddress::from_str("0xzz").unwrap_err();
+        assert!(matches!(&err, Error::MemAddressParsing(s) if s == "0xzz"));
+        assert!(err.to_string().contains("0xzz"));
+    }
+
+    #[test]
+    fn mem_addr_numeric_order() -> Result<(), Error> {
+        // Lexicographically "10" < "100" < "9", numerically 0x9 < 0x10 < 0x100.
+        let memory: std::collections::BTreeMap<MemoryAddress, Word> = ["100", "9", "10"]
+            .iter()
+            .map(|addr| Ok((MemoryAddress::from_str(addr)?, Word::zero())))
+            .collect::<Result<_, Error>>()?;
+
+        assert_eq!(
+            memory.keys().copied().collect::<Vec<_>>(),
+            vec![
+                MemoryAddress(0x9),
+                MemoryAddress(0x10),
+                MemoryAddress(0x100)
+            ]
+        );
+        assert_eq!(memory.keys().last(), Some(&MemoryAddress(0x100)));
+        Ok(())
+    }
+
     #[test]
     fn mem_addr_bytes_serialization_trip() -> Result<(), Error> {
         let first_usize = 64536usize;
@@ -391,4 +425,19 @@ mod memory_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn memory_size() {
+        assert_eq!(Memory::new().memory_size(), 0);
+
+        let memory = Memory::from(vec![Word::from(1)]);
+        assert_eq!(memory.memory_size(), 32);
+
+        // The top address 0x20 is in the second word.
+        let mut memory = Memory::new();
+        memory.push([0u8; 33]);
+        assert_eq!(memory.memory_size(), 64);
+        memory.extend_at_least(33);
+        assert_eq!(memory.memory_size(), memory.len() as u64);
+    }
 }
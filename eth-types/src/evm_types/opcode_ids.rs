@@ -6,10 +6,11 @@ use regex::Regex;
 use serde::{de, Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 /// Opcode enum. One-to-one corresponding to an `u8` value.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Hash, EnumIter)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, EnumIter)]
 pub enum OpcodeId {
     /// `STOP`
     STOP,
@@ -316,6 +317,12 @@ pub enum OpcodeId {
 }
 
 impl OpcodeId {
+    /// Returns an iterator over every defined `OpcodeId`, core and external
+    /// ones, each once and without [`OpcodeId::INVALID`].
+    pub fn all() -> impl Iterator<Item = OpcodeId> {
+        Self::iter().filter(|op| !matches!(op, OpcodeId::INVALID(_)))
+    }
+
     /// Returns `true` if the `OpcodeId` is a `PUSHn`.
     pub fn is_push(&self) -> bool {
         self.as_u8() >= Self::PUSH1.as_u8() && self.as_u8() <= Self::PUSH32.as_u8()
@@ -359,6 +366,221 @@ impl OpcodeId {
     }
 }
 
+impl OpcodeId {
+    /// Arithmetic opcodes, from `ADD` to `SIGNEXTEND`.
+    pub const ARITHMETIC: &'static [OpcodeId] = &[
+        OpcodeId::ADD,
+        OpcodeId::MUL,
+        OpcodeId::SUB,
+        OpcodeId::DIV,
+        OpcodeId::SDIV,
+        OpcodeId::MOD,
+        OpcodeId::SMOD,
+        OpcodeId::ADDMOD,
+        OpcodeId::MULMOD,
+        OpcodeId::EXP,
+        OpcodeId::SIGNEXTEND,
+    ];
+
+    /// Comparison opcodes, from `LT` to `ISZERO`.
+    pub const COMPARISON: &'static [OpcodeId] = &[
+        OpcodeId::LT,
+        OpcodeId::GT,
+        OpcodeId::SLT,
+        OpcodeId::SGT,
+        OpcodeId::EQ,
+        OpcodeId::ISZERO,
+    ];
+
+    /// Bitwise logic and shift opcodes.
+    pub const BITWISE: &'static [OpcodeId] = &[
+        OpcodeId::AND,
+        OpcodeId::OR,
+        OpcodeId::XOR,
+        OpcodeId::NOT,
+        OpcodeId::BYTE,
+        OpcodeId::SHL,
+        OpcodeId::SHR,
+        OpcodeId::SAR,
+    ];
+
+    /// The hashing opcode `SHA3`.
+    pub const KECCAK: &'static [OpcodeId] = &[OpcodeId::SHA3];
+
+    /// Opcodes reading the environment of the current call or of other
+    /// accounts.
+    pub const ENVIRONMENT: &'static [OpcodeId] = &[
+        OpcodeId::ADDRESS,
+        OpcodeId::BALANCE,
+        OpcodeId::ORIGIN,
+        OpcodeId::CALLER,
+        OpcodeId::CALLVALUE,
+        OpcodeId::CALLDATALOAD,
+        OpcodeId::CALLDATASIZE,
+        OpcodeId::CALLDATACOPY,
+        OpcodeId::CODESIZE,
+        OpcodeId::CODECOPY,
+        OpcodeId::GASPRICE,
+        OpcodeId::EXTCODESIZE,
+        OpcodeId::EXTCODECOPY,
+        OpcodeId::RETURNDATASIZE,
+        OpcodeId::RETURNDATACOPY,
+        OpcodeId::EXTCODEHASH,
+    ];
+
+    /// Opcodes reading the current block, the chain or the balance of the
+    /// callee.
+    pub const BLOCK_INFO: &'static [OpcodeId] = &[
+        OpcodeId::BLOCKHASH,
+        OpcodeId::COINBASE,
+        OpcodeId::TIMESTAMP,
+        OpcodeId::NUMBER,
+        OpcodeId::DIFFICULTY,
+        OpcodeId::GASLIMIT,
+        OpcodeId::CHAINID,
+        OpcodeId::SELFBALANCE,
+        OpcodeId::BASEFEE,
+    ];
+
+    /// Stack, memory, storage and flow opcodes, without the `PUSHn`, `DUPn` and
+    /// `SWAPn` ones.
+    pub const STACK_MEMORY_STORAGE_FLOW: &'static [OpcodeId] = &[
+        OpcodeId::POP,
+        OpcodeId::MLOAD,
+        OpcodeId::MSTORE,
+        OpcodeId::MSTORE8,
+        OpcodeId::SLOAD,
+        OpcodeId::SSTORE,
+        OpcodeId::JUMP,
+        OpcodeId::JUMPI,
+        OpcodeId::PC,
+        OpcodeId::MSIZE,
+        OpcodeId::GAS,
+        OpcodeId::JUMPDEST,
+    ];
+
+    /// All the `PUSHn` opcodes, by increasing `n`.
+    pub const PUSH_ALL: &'static [OpcodeId] = &[
+        OpcodeId::PUSH1,
+        OpcodeId::PUSH2,
+        OpcodeId::PUSH3,
+        OpcodeId::PUSH4,
+        OpcodeId::PUSH5,
+        OpcodeId::PUSH6,
+        OpcodeId::PUSH7,
+        OpcodeId::PUSH8,
+        OpcodeId::PUSH9,
+        OpcodeId::PUSH10,
+        OpcodeId::PUSH11,
+        OpcodeId::PUSH12,
+        OpcodeId::PUSH13,
+        OpcodeId::PUSH14,
+        OpcodeId::PUSH15,
+        OpcodeId::PUSH16,
+        OpcodeId::PUSH17,
+        OpcodeId::PUSH18,
+        OpcodeId::PUSH19,
+        OpcodeId::PUSH20,
+        OpcodeId::PUSH21,
+        OpcodeId::PUSH22,
+        OpcodeId::PUSH23,
+        OpcodeId::PUSH24,
+        OpcodeId::PUSH25,
+        OpcodeId::PUSH26,
+        OpcodeId::PUSH27,
+        OpcodeId::PUSH28,
+        OpcodeId::PUSH29,
+        OpcodeId::PUSH30,
+        OpcodeId::PUSH31,
+        OpcodeId::PUSH32,
+    ];
+
+    /// All the `DUPn` opcodes, by increasing `n`.
+    pub const DUP_ALL: &'static [OpcodeId] = &[
+        OpcodeId::DUP1,
+        OpcodeId::DUP2,
+        OpcodeId::DUP3,
+        OpcodeId::DUP4,
+        OpcodeId::DUP5,
+        OpcodeId::DUP6,
+        OpcodeId::DUP7,
+        OpcodeId::DUP8,
+        OpcodeId::DUP9,
+        OpcodeId::DUP10,
+        OpcodeId::DUP11,
+        OpcodeId::DUP12,
+        OpcodeId::DUP13,
+        OpcodeId::DUP14,
+        OpcodeId::DUP15,
+        OpcodeId::DUP16,
+    ];
+
+    /// All the `SWAPn` opcodes, by increasing `n`.
+    pub const SWAP_ALL: &'static [OpcodeId] = &[
+        OpcodeId::SWAP1,
+        OpcodeId::SWAP2,
+        OpcodeId::SWAP3,
+        OpcodeId::SWAP4,
+        OpcodeId::SWAP5,
+        OpcodeId::SWAP6,
+        OpcodeId::SWAP7,
+        OpcodeId::SWAP8,
+        OpcodeId::SWAP9,
+        OpcodeId::SWAP10,
+        OpcodeId::SWAP11,
+        OpcodeId::SWAP12,
+        OpcodeId::SWAP13,
+        OpcodeId::SWAP14,
+        OpcodeId::SWAP15,
+        OpcodeId::SWAP16,
+    ];
+
+    /// All the `LOGn` opcodes, by increasing `n`.
+    pub const LOG_ALL: &'static [OpcodeId] = &[
+        OpcodeId::LOG0,
+        OpcodeId::LOG1,
+        OpcodeId::LOG2,
+        OpcodeId::LOG3,
+        OpcodeId::LOG4,
+    ];
+
+    /// Opcodes creating a new call context, see
+    /// [`OpcodeId::is_call_or_create`].
+    pub const CALL_FAMILY: &'static [OpcodeId] = &[
+        OpcodeId::CREATE,
+        OpcodeId::CALL,
+        OpcodeId::CALLCODE,
+        OpcodeId::DELEGATECALL,
+        OpcodeId::CREATE2,
+        OpcodeId::STATICCALL,
+    ];
+
+    /// Opcodes halting the current call successfully or reverting it.
+    pub const HALTING: &'static [OpcodeId] = &[
+        OpcodeId::STOP,
+        OpcodeId::RETURN,
+        OpcodeId::REVERT,
+        OpcodeId::SELFDESTRUCT,
+    ];
+
+    /// All the opcode groups above, which partition [`OpcodeId::all`].
+    pub const GROUPS: &'static [&'static [OpcodeId]] = &[
+        Self::ARITHMETIC,
+        Self::COMPARISON,
+        Self::BITWISE,
+        Self::KECCAK,
+        Self::ENVIRONMENT,
+        Self::BLOCK_INFO,
+        Self::STACK_MEMORY_STORAGE_FLOW,
+        Self::PUSH_ALL,
+        Self::DUP_ALL,
+        Self::SWAP_ALL,
+        Self::LOG_ALL,
+        Self::CALL_FAMILY,
+        Self::HALTING,
+    ];
+}
+
 impl OpcodeId {
     /// Returns the `OpcodeId` as a `u8`.
     pub const fn as_u8(&self) -> u8 {
@@ -514,6 +736,11 @@ impl OpcodeId {
         self.as_u8() as u64
     }
 
+    /// Returns the `OpcodeId` as a `usize`.
+    pub const fn as_usize(&self) -> usize {
+        self.as_u8() as usize
+    }
+
     /// Returns the constant gas cost of `OpcodeId`
     pub const fn constant_gas_cost(&self) -> GasCost {
         match self {
@@ -663,6 +890,54 @@ impl OpcodeId {
         }
     }
 
+    /// Returns `true` if the gas cost of `OpcodeId` has a dynamic portion on
+    /// top of [`OpcodeId::constant_gas_cost`], like memory expansion, cold
+    /// account or storage access, or a copy length dependent cost.
+    pub const fn has_dynamic_gas(&self) -> bool {
+        matches!(
+            self,
+            OpcodeId::EXP
+                | OpcodeId::SHA3
+                | OpcodeId::BALANCE
+                | OpcodeId::CALLDATACOPY
+                | OpcodeId::CODECOPY
+                | OpcodeId::EXTCODESIZE
+                | OpcodeId::EXTCODECOPY
+                | OpcodeId::RETURNDATACOPY
+                | OpcodeId::EXTCODEHASH
+                | OpcodeId::MLOAD
+                | OpcodeId::MSTORE
+                | OpcodeId::MSTORE8
+                | OpcodeId::SLOAD
+                | OpcodeId::SSTORE
+                | OpcodeId::LOG0
+                | OpcodeId::LOG1
+                | OpcodeId::LOG2
+                | OpcodeId::LOG3
+                | OpcodeId::LOG4
+                | OpcodeId::CREATE
+                | OpcodeId::CALL
+                | OpcodeId::CALLCODE
+                | OpcodeId::RETURN
+                | OpcodeId::DELEGATECALL
+                | OpcodeId::CREATE2
+                | OpcodeId::STATICCALL
+                | OpcodeId::REVERT
+                | OpcodeId::SELFDESTRUCT
+        )
+    }
+
+    /// Returns the full gas cost of `OpcodeId` if it's constant, or `None` if
+    /// it [has a dynamic portion](OpcodeId::has_dynamic_gas), in which case
+    /// [`OpcodeId::constant_gas_cost`] is only the static part of it.
+    pub const fn fixed_gas_cost(&self) -> Option<GasCost> {
+        if self.has_dynamic_gas() {
+            None
+        } else {
+            Some(self.constant_gas_cost())
+        }
+    }
+
     /// Returns the constant min & stack pointer of `OpcodeId`
     pub const fn valid_stack_ptr_range(&self) -> (u32, u32) {
         match self {
@@ -819,6 +1094,100 @@ impl OpcodeId {
         }
     }
 
+    /// Returns the number of words popped from the stack by `OpcodeId`.
+    /// `DUPn` and `SWAPn` only read and modify the stack at depth `n` (and
+    /// `n + 1` for `SWAPn`), so they don't pop any word.
+    pub fn stack_pops(&self) -> usize {
+        if self.is_push() || self.is_dup() || self.is_swap() {
+            return 0;
+        }
+        if self.is_log() {
+            // offset, length and the topics
+            return self.as_usize() - OpcodeId::LOG0.as_usize() + 2;
+        }
+        match self {
+            OpcodeId::ISZERO
+            | OpcodeId::NOT
+            | OpcodeId::BALANCE
+            | OpcodeId::CALLDATALOAD
+            | OpcodeId::EXTCODESIZE
+            | OpcodeId::EXTCODEHASH
+            | OpcodeId::BLOCKHASH
+            | OpcodeId::POP
+            | OpcodeId::MLOAD
+            | OpcodeId::SLOAD
+            | OpcodeId::JUMP
+            | OpcodeId::SELFDESTRUCT => 1,
+            OpcodeId::ADD
+            | OpcodeId::MUL
+            | OpcodeId::SUB
+            | OpcodeId::DIV
+            | OpcodeId::SDIV
+            | OpcodeId::MOD
+            | OpcodeId::SMOD
+            | OpcodeId::EXP
+            | OpcodeId::SIGNEXTEND
+            | OpcodeId::LT
+            | OpcodeId::GT
+            | OpcodeId::SLT
+            | OpcodeId::SGT
+            | OpcodeId::EQ
+            | OpcodeId::AND
+            | OpcodeId::OR
+            | OpcodeId::XOR
+            | OpcodeId::BYTE
+            | OpcodeId::SHL
+            | OpcodeId::SHR
+            | OpcodeId::SAR
+            | OpcodeId::SHA3
+            | OpcodeId::MSTORE
+            | OpcodeId::MSTORE8
+            | OpcodeId::SSTORE
+            | OpcodeId::JUMPI
+            | OpcodeId::RETURN
+            | OpcodeId::REVERT => 2,
+            OpcodeId::ADDMOD
+            | OpcodeId::MULMOD
+            | OpcodeId::CALLDATACOPY
+            | OpcodeId::CODECOPY
+            | OpcodeId::RETURNDATACOPY
+            | OpcodeId::CREATE => 3,
+            OpcodeId::EXTCODECOPY | OpcodeId::CREATE2 => 4,
+            OpcodeId::DELEGATECALL | OpcodeId::STATICCALL => 6,
+            OpcodeId::CALL | OpcodeId::CALLCODE => 7,
+            // Opcodes that only push, `STOP`, `JUMPDEST` and `INVALID`
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of words pushed to the stack by `OpcodeId`.
+    /// `DUPn` pushes a copy of the word at depth `n`, while `SWAPn` doesn't
+    /// push any word.
+    pub fn stack_pushes(&self) -> usize {
+        if self.is_swap() || self.is_log() {
+            return 0;
+        }
+        match self {
+            OpcodeId::STOP
+            | OpcodeId::CALLDATACOPY
+            | OpcodeId::CODECOPY
+            | OpcodeId::EXTCODECOPY
+            | OpcodeId::RETURNDATACOPY
+            | OpcodeId::POP
+            | OpcodeId::MSTORE
+            | OpcodeId::MSTORE8
+            | OpcodeId::SSTORE
+            | OpcodeId::JUMP
+            | OpcodeId::JUMPI
+            | OpcodeId::JUMPDEST
+            | OpcodeId::RETURN
+            | OpcodeId::REVERT
+            | OpcodeId::INVALID(_)
+            | OpcodeId::SELFDESTRUCT => 0,
+            _ => 1,
+        }
+    }
+
     /// Returns `true` if the `OpcodeId` has memory access
     pub const fn has_memory_access(&self) -> bool {
         matches!(
@@ -858,8 +1227,8 @@ impl OpcodeId {
     }
 
     /// Returns number of bytes used by immediate data. This is > 0 only for
-    /// push opcodes.
-    pub fn data_len(&self) -> usize {
+    /// push opcodes, where it's in `1..=32`.
+    pub fn push_data_len(&self) -> usize {
         if self.is_push() {
             (self.as_u8() - OpcodeId::PUSH1.as_u8() + 1) as usize
         } else {
@@ -1173,8 +1542,11 @@ impl FromStr for OpcodeId {
                 lazy_static! {
                     static ref RE: Regex = Regex::new("opcode 0x([[:xdigit:]]{1,2}) not defined")
                         .expect("invalid regex");
+                    // Format used by the `Display` implementation
+                    static ref RE_DISPLAY: Regex = Regex::new(r"^INVALID\(0x([[:xdigit:]]{2})\)$")
+                        .expect("invalid regex");
                 }
-                if let Some(cap) = RE.captures(s) {
+                if let Some(cap) = RE.captures(s).or_else(|| RE_DISPLAY.captures(s)) {
                     if let Some(byte_hex) = cap.get(1).map(|m| m.as_str()) {
                         return Ok(OpcodeId::INVALID(
                             u8::from_str_radix(byte_hex, 16).expect("invalid hex byte from regex"),
@@ -1187,6 +1559,17 @@ impl FromStr for OpcodeId {
     }
 }
 
+impl Serialize for OpcodeId {
+    /// Serializes the mnemonic of the opcode, as geth does in its traces.  Use
+    /// [`serde_byte`] to serialize the raw byte instead.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 impl<'de> Deserialize<'de> for OpcodeId {
     fn deserialize<D>(deserializer: D) -> Result<OpcodeId, D::Error>
     where
@@ -1198,8 +1581,37 @@ impl<'de> Deserialize<'de> for OpcodeId {
 }
 
 impl fmt::Display for OpcodeId {
+    /// Prints the mnemonic of the opcode, which can be parsed back with
+    /// [`OpcodeId::from_str`].  Unassigned bytes are printed as
+    /// `INVALID(0xNN)`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            OpcodeId::INVALID(b) => write!(f, "INVALID(0x{:02x})", b),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// (De)serialization of an `OpcodeId` as its raw byte, to be used with
+/// `#[serde(with = "eth_types::evm_types::opcode_ids::serde_byte")]`.
+pub mod serde_byte {
+    use super::OpcodeId;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the byte of the opcode.
+    pub fn serialize<S>(op: &OpcodeId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(op.as_u8())
+    }
+
+    /// Deserializes an opcode from its byte.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<OpcodeId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u8::deserialize(deserializer).map(OpcodeId::from)
     }
 }
 
@@ -1230,10 +1642,156 @@ mod opcode_ids_tests {
     }
 
     #[test]
-    fn data_len() {
-        assert_eq!(OpcodeId::PUSH1.data_len(), 1);
-        assert_eq!(OpcodeId::PUSH10.data_len(), 10);
-        assert_eq!(OpcodeId::LOG2.data_len(), 0);
-        assert_eq!(OpcodeId::CALLCODE.data_len(), 0);
+    fn is_push() {
+        assert!(OpcodeId::PUSH1.is_push());
+        assert!(OpcodeId::PUSH32.is_push());
+        assert!(!OpcodeId::ADD.is_push());
+        assert!(!OpcodeId::DUP1.is_push());
+    }
+
+    #[test]
+    fn push_data_len() {
+        assert_eq!(OpcodeId::PUSH1.push_data_len(), 1);
+        assert_eq!(OpcodeId::PUSH10.push_data_len(), 10);
+        assert_eq!(OpcodeId::PUSH32.push_data_len(), 32);
+        assert_eq!(OpcodeId::ADD.push_data_len(), 0);
+        assert_eq!(OpcodeId::LOG2.push_data_len(), 0);
+        assert_eq!(OpcodeId::CALLCODE.push_data_len(), 0);
+    }
+
+    #[test]
+    fn serde_mnemonic_roundtrip() {
+        for (op, json) in [
+            (OpcodeId::PUSH1, "\"PUSH1\""),
+            (OpcodeId::SSTORE, "\"SSTORE\""),
+            (OpcodeId::MSTORE, "\"MSTORE\""),
+            (OpcodeId::INVALID(0xfe), "\"INVALID(0xfe)\""),
+        ] {
+            assert_eq!(serde_json::to_string(&op).unwrap(), json);
+            assert_eq!(serde_json::from_str::<OpcodeId>(json).unwrap(), op);
+        }
+
+        for op in OpcodeId::all() {
+            let json = serde_json::to_string(&op).unwrap();
+            assert_eq!(json, format!("\"{}\"", op));
+            assert_eq!(serde_json::from_str::<OpcodeId>(&json).unwrap(), op);
+        }
+    }
+
+    #[test]
+    fn serde_byte_roundtrip() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "serde_byte")] OpcodeId);
+
+        for op in [OpcodeId::PUSH1, OpcodeId::SSTORE] {
+            let json = serde_json::to_string(&Wrapper(op)).unwrap();
+            assert_eq!(json, op.as_u8().to_string());
+            assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), Wrapper(op));
+        }
+    }
+
+    #[test]
+    fn opcode_parsing_error() {
+        let err = OpcodeId::from_str("PUSH33").unwrap_err();
+        assert!(matches!(&err, Error::OpcodeParsing(s) if s == "PUSH33"));
+        assert!(err.to_string().contains("PUSH33"));
+    }
+
+    #[test]
+    fn all() {
+        let ops: Vec<_> = OpcodeId::all().collect();
+        // 106 core and 36 external opcodes are declared.
+        assert_eq!(ops.len(), 142);
+        let bytes: std::collections::HashSet<_> = ops.iter().map(|op| op.as_u8()).collect();
+        assert_eq!(bytes.len(), ops.len());
+        for op in ops {
+            assert_eq!(OpcodeId::from(op.as_u8()), op);
+        }
+    }
+
+    #[test]
+    fn groups_partition_all() {
+        let mut grouped = std::collections::HashSet::new();
+        for group in OpcodeId::GROUPS {
+            for op in group.iter() {
+                assert!(grouped.insert(*op), "{:?} is in more than one group", op);
+            }
+        }
+        for op in OpcodeId::all() {
+            assert!(grouped.contains(&op), "{:?} is in no group", op);
+        }
+        assert_eq!(grouped.len(), OpcodeId::all().count());
+
+        assert!(OpcodeId::PUSH_ALL.iter().all(OpcodeId::is_push));
+        assert!(OpcodeId::DUP_ALL.iter().all(OpcodeId::is_dup));
+        assert!(OpcodeId::SWAP_ALL.iter().all(OpcodeId::is_swap));
+        assert!(OpcodeId::LOG_ALL.iter().all(OpcodeId::is_log));
+        assert!(OpcodeId::CALL_FAMILY
+            .iter()
+            .all(OpcodeId::is_call_or_create));
+    }
+
+    #[test]
+    fn fixed_gas_cost() {
+        assert_eq!(OpcodeId::ADD.fixed_gas_cost(), Some(GasCost::FASTEST));
+        assert_eq!(OpcodeId::PUSH1.fixed_gas_cost(), Some(GasCost::FASTEST));
+        assert_eq!(OpcodeId::MUL.fixed_gas_cost(), Some(GasCost::FAST));
+        assert_eq!(OpcodeId::JUMPDEST.fixed_gas_cost(), Some(GasCost::ONE));
+        assert_eq!(OpcodeId::STOP.fixed_gas_cost(), Some(GasCost::ZERO));
+        assert_eq!(OpcodeId::SHA3.fixed_gas_cost(), None);
+        assert_eq!(OpcodeId::CALL.fixed_gas_cost(), None);
+        assert_eq!(OpcodeId::SSTORE.fixed_gas_cost(), None);
+        assert_eq!(OpcodeId::MLOAD.fixed_gas_cost(), None);
+        // The static portion is still available for dynamic gas opcodes
+        assert_eq!(OpcodeId::SHA3.constant_gas_cost(), GasCost::SHA3);
+    }
+
+    #[test]
+    fn stack_pops_pushes() {
+        for (opcode, pops, pushes) in [
+            (OpcodeId::STOP, 0, 0),
+            (OpcodeId::ADD, 2, 1),
+            (OpcodeId::ADDMOD, 3, 1),
+            (OpcodeId::LT, 2, 1),
+            (OpcodeId::ISZERO, 1, 1),
+            (OpcodeId::NOT, 1, 1),
+            (OpcodeId::SAR, 2, 1),
+            (OpcodeId::CALLER, 0, 1),
+            (OpcodeId::CALLDATACOPY, 3, 0),
+            (OpcodeId::EXTCODECOPY, 4, 0),
+            (OpcodeId::POP, 1, 0),
+            (OpcodeId::MLOAD, 1, 1),
+            (OpcodeId::SSTORE, 2, 0),
+            (OpcodeId::JUMPI, 2, 0),
+            (OpcodeId::PUSH1, 0, 1),
+            (OpcodeId::PUSH32, 0, 1),
+            (OpcodeId::DUP3, 0, 1),
+            (OpcodeId::SWAP16, 0, 0),
+            (OpcodeId::LOG0, 2, 0),
+            (OpcodeId::LOG4, 6, 0),
+            (OpcodeId::CALL, 7, 1),
+            (OpcodeId::STATICCALL, 6, 1),
+            (OpcodeId::CREATE2, 4, 1),
+            (OpcodeId::RETURN, 2, 0),
+            (OpcodeId::INVALID(0xfe), 0, 0),
+        ] {
+            assert_eq!(opcode.stack_pops(), pops, "{} pops", opcode);
+            assert_eq!(opcode.stack_pushes(), pushes, "{} pushes", opcode);
+        }
+    }
+
+    #[test]
+    fn display_from_str_roundtrip() {
+        for b in 0..=u8::MAX {
+            let opcode = OpcodeId::from(b);
+            let mnemonic = opcode.to_string();
+            let parsed = OpcodeId::from_str(&mnemonic).unwrap();
+            assert_eq!(parsed, opcode, "{} doesn't roundtrip", mnemonic);
+            assert_eq!(parsed.to_string(), mnemonic);
+        }
+        assert_eq!(OpcodeId::PUSH1.to_string(), "PUSH1");
+        assert_eq!(OpcodeId::MLOAD.to_string(), "MLOAD");
+        assert_eq!(OpcodeId::INVALID(0xfe).to_string(), "INVALID(0xfe)");
+        assert_eq!(OpcodeId::INVALID(0x0c).to_string(), "INVALID(0x0c)");
     }
 }
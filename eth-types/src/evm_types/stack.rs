@@ -42,8 +42,8 @@ impl FromStr for StackAddress {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.strip_prefix("0x").unwrap_or(s);
-        let value = usize::from_str_radix(s, 16).map_err(|_| Error::StackAddressParsing)?;
+        let value = usize::from_str_radix(s.strip_prefix("0x").unwrap_or(s), 16)
+            .map_err(|_| Error::StackAddressParsing(s.to_string()))?;
         // Stack only has 1023 slots avaliable.
         if value >= 1024 {
             return Err(Error::InvalidStackPointer);
@@ -125,15 +125,23 @@ impl Stack {
 
     /// Returns the last [`Word`] allocated in the `Stack`.
     pub fn last(&self) -> Result<Word, Error> {
-        self.0.last().cloned().ok_or(Error::InvalidStackPointer)
+        self.peek(0).cloned()
     }
 
-    /// Returns the second last [`Word`] allocated in the `Stack`.
+    /// Returns the nth last [`Word`] allocated in the `Stack`.
     pub fn nth_last(&self, nth: usize) -> Result<Word, Error> {
+        self.peek(nth).cloned()
+    }
+
+    /// Returns a reference to the [`Word`] at `depth` in the `Stack`, where
+    /// depth 0 is the top of the stack, or [`Error::StackUnderflow`] if the
+    /// stack doesn't hold that many words.
+    pub fn peek(&self, depth: usize) -> Result<&Word, Error> {
         self.0
-            .get(self.0.len() - (nth + 1))
-            .cloned()
-            .ok_or(Error::InvalidStackPointer)
+            .len()
+            .checked_sub(depth + 1)
+            .and_then(|index| self.0.get(index))
+            .ok_or(Error::StackUnderflow(depth))
     }
 }
 
@@ -161,6 +169,13 @@ mod stack_tests {
         Ok(())
     }
 
+    #[test]
+    fn stack_addr_parsing_error() {
+        let err = StackAddress::from_str("0xgg").unwrap_err();
+        assert!(matches!(&err, Error::StackAddressParsing(s) if s == "0xgg"));
+        assert!(err.to_string().contains("0xgg"));
+    }
+
     #[test]
     fn stack_pointer() -> Result<(), Error> {
         let stack = setup_stack(["0x15", "0x16", "0x17"]);
@@ -179,4 +194,15 @@ mod stack_tests {
         assert_eq!(stack.nth_last(1).unwrap(), word!("0x16"));
         Ok(())
     }
+
+    #[test]
+    fn stack_peek() {
+        let stack = setup_stack(["0x15", "0x16", "0x17"]);
+
+        assert_eq!(stack.peek(0), Ok(&word!("0x17")));
+        assert_eq!(stack.peek(2), Ok(&word!("0x15")));
+        assert_eq!(stack.peek(3), Err(Error::StackUnderflow(3)));
+        assert_eq!(stack.nth_last(3), Err(Error::StackUnderflow(3)));
+        assert_eq!(Stack::new().last(), Err(Error::StackUnderflow(0)));
+    }
 }
@@ -1,6 +1,7 @@
 //! Doc this
 use crate::{DebugWord, Word};
 use crate::{Error, ToBigEndian};
+use itertools::Itertools;
 use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
 use std::collections::HashMap;
@@ -31,7 +32,8 @@ impl Serialize for Storage {
         S: Serializer,
     {
         let mut ser = serializer.serialize_map(Some(self.0.len()))?;
-        for (k, v) in self.0.iter() {
+        // Sort the slots so that serialized traces are deterministic.
+        for (k, v) in self.0.iter().sorted_by_key(|(k, _)| *k) {
             ser.serialize_entry(&hex::encode(k.to_be_bytes()), &hex::encode(v.to_be_bytes()))?;
         }
         ser.end()
@@ -66,3 +68,29 @@ impl Storage {
         self.get(key).cloned().ok_or(Error::InvalidStorageKey)
     }
 }
+
+#[cfg(test)]
+mod storage_tests {
+    use super::*;
+    use crate::word;
+
+    #[test]
+    fn storage_serialization_is_sorted() {
+        let storage = Storage::new(HashMap::from([
+            (word!("0x2"), word!("0x20")),
+            (word!("0x1"), word!("0x10")),
+            (word!("0x3"), word!("0x30")),
+        ]));
+        let json = serde_json::to_string(&storage).unwrap();
+        let keys: Vec<_> = ["01", "02", "03"]
+            .iter()
+            .map(|k| format!("{:0>64}", k))
+            .collect();
+        let positions: Vec<_> = keys
+            .iter()
+            .map(|k| json.find(k.as_str()).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(storage.get(&word!("0x1")), Some(&word!("0x10")));
+    }
+}
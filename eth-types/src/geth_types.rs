@@ -150,8 +150,8 @@ impl From<&Transaction> for crate::Transaction {
             gas: tx.gas_limit,
             value: tx.value,
             gas_price: Some(tx.gas_price),
-            max_priority_fee_per_gas: Some(tx.gas_fee_cap),
-            max_fee_per_gas: Some(tx.gas_tip_cap),
+            max_priority_fee_per_gas: Some(tx.gas_tip_cap),
+            max_fee_per_gas: Some(tx.gas_fee_cap),
             input: tx.call_data.clone(),
             access_list: tx.access_list.clone(),
             v: tx.v.into(),
@@ -171,8 +171,8 @@ impl From<&crate::Transaction> for Transaction {
             gas_limit: tx.gas,
             value: tx.value,
             gas_price: tx.gas_price.unwrap_or_default(),
-            gas_fee_cap: tx.max_priority_fee_per_gas.unwrap_or_default(),
-            gas_tip_cap: tx.max_fee_per_gas.unwrap_or_default(),
+            gas_fee_cap: tx.max_fee_per_gas.unwrap_or_default(),
+            gas_tip_cap: tx.max_priority_fee_per_gas.unwrap_or_default(),
             call_data: tx.input.clone(),
             access_list: tx.access_list.clone(),
             v: tx.v.as_u64(),
@@ -196,7 +196,43 @@ impl From<&Transaction> for TransactionRequest {
 }
 
 impl Transaction {
-    /// Return the SignData associated with this Transaction.
+    /// Whether this [`Transaction`] creates a contract, which is the case
+    /// when it has no callee.
+    pub fn is_create(&self) -> bool {
+        self.to.is_none()
+    }
+
+    /// Decodes the signature `v` into the recovery id and, for [EIP-155]
+    /// transactions, the chain id it embeds as `v = chain_id * 2 + 35 +
+    /// recovery_id`.  Pre-EIP-155 transactions have `v = 27 + recovery_id`.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn decode_v(&self) -> Result<(u8, Option<u64>), Error> {
+        match self.v {
+            27 | 28 => Ok(((self.v - 27) as u8, None)),
+            v if v >= 35 => Ok((((v - 35) % 2) as u8, Some((v - 35) / 2))),
+            _ => Err(Error::Signature(libsecp256k1::Error::InvalidSignature)),
+        }
+    }
+
+    /// Return the RLP encoded message signed by this Transaction, which is
+    /// rlp([nonce, gasPrice, gas, to, value, data, chain_id, 0, 0]), or
+    /// rlp([nonce, gasPrice, gas, to, value, data]) before EIP-155.  EIP-155
+    /// transactions must embed `chain_id` in their signature.
+    pub fn sign_msg(&self, chain_id: u64) -> Result<Vec<u8>, Error> {
+        let (_, tx_chain_id) = self.decode_v()?;
+        let req: TransactionRequest = self.into();
+        match tx_chain_id {
+            Some(tx_chain_id) if tx_chain_id == chain_id => {
+                Ok(req.chain_id(chain_id).rlp().to_vec())
+            }
+            Some(_) => Err(Error::Signature(libsecp256k1::Error::InvalidSignature)),
+            None => Ok(req.rlp().to_vec()),
+        }
+    }
+
+    /// Return the SignData associated with this Transaction.  EIP-155
+    /// transactions must embed `chain_id` in their signature.
     pub fn sign_data(&self, chain_id: u64) -> Result<SignData, Error> {
         let sig_r_le = self.r.to_le_bytes();
         let sig_s_le = self.s.to_le_bytes();
@@ -208,18 +244,13 @@ impl Transaction {
             secp256k1::Fq::from_repr(sig_s_le),
             Error::Signature(libsecp256k1::Error::InvalidSignature),
         )?;
-        // msg = rlp([nonce, gasPrice, gas, to, value, data, sig_v, r, s])
-        let req: TransactionRequest = self.into();
-        let msg = req.chain_id(chain_id).rlp();
+        let (v, _) = self.decode_v()?;
+        let msg = self.sign_msg(chain_id)?;
         let msg_hash: [u8; 32] = Keccak256::digest(&msg)
             .as_slice()
             .to_vec()
             .try_into()
             .expect("hash length isn't 32 bytes");
-        let v = self
-            .v
-            .checked_sub(35 + chain_id * 2)
-            .ok_or(Error::Signature(libsecp256k1::Error::InvalidSignature))? as u8;
         let pk = recover_pk(v, &self.r, &self.s, &msg_hash)?;
         // msg_hash = msg_hash % q
         let msg_hash = BigUint::from_bytes_be(msg_hash.as_slice());
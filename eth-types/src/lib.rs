@@ -88,6 +88,29 @@ pub trait ToLittleEndian {
     fn to_le_bytes(&self) -> [u8; 32];
 }
 
+/// Trait used to access the bits of a 256 bit value.
+pub trait WordBits {
+    /// Returns the bit at `index`, counting from the least significant one,
+    /// or [`Error::BitIndexOutOfRange`] if `index >= 256`.
+    fn checked_bit(&self, index: usize) -> Result<bool, Error>;
+
+    /// Returns the most significant bit, which is the sign of the value as a
+    /// two's complement signed integer.
+    fn msb(&self) -> bool;
+}
+
+/// Trait used to convert a 256 bit value from and to its four 64 bit limbs.
+/// The limbs are in little endian order, the limb at index 0 holding the
+/// least significant 64 bits, which is the order of the limbs the arithmetic
+/// gadgets build from the little endian bytes of a word.
+pub trait WordLimbs: Sized {
+    /// Returns the 64 bit limbs of the value, in little endian order.
+    fn to_u64_limbs(&self) -> [u64; 4];
+
+    /// Builds the value from its 64 bit limbs, in little endian order.
+    fn from_u64_limbs(limbs: [u64; 4]) -> Self;
+}
+
 // We use our own declaration of another U256 in order to implement a custom
 // deserializer that can parse U256 when returned by structLogs fields in geth
 // debug_trace* methods, which don't contain the `0x` prefix.
@@ -106,7 +129,7 @@ impl<'de> Deserialize<'de> for DebugU256 {
         D: serde::Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        DebugU256::from_str(&s).map_err(de::Error::custom)
+        DebugU256::from_str(&s).map_err(|_| de::Error::custom(Error::EvmWordParsing(s)))
     }
 }
 
@@ -154,6 +177,30 @@ impl ToLittleEndian for U256 {
     }
 }
 
+impl WordBits for U256 {
+    fn checked_bit(&self, index: usize) -> Result<bool, Error> {
+        if index < 256 {
+            Ok(self.bit(index))
+        } else {
+            Err(Error::BitIndexOutOfRange(index))
+        }
+    }
+
+    fn msb(&self) -> bool {
+        self.bit(255)
+    }
+}
+
+impl WordLimbs for U256 {
+    fn to_u64_limbs(&self) -> [u64; 4] {
+        self.0
+    }
+
+    fn from_u64_limbs(limbs: [u64; 4]) -> Self {
+        U256(limbs)
+    }
+}
+
 impl<F: Field> ToScalar<F> for U256 {
     fn to_scalar(&self) -> Option<F> {
         let mut bytes = [0u8; 32];
@@ -410,6 +457,32 @@ pub struct GethExecTrace {
     pub struct_logs: Vec<GethExecStep>,
 }
 
+impl GethExecTrace {
+    /// Check that the static JUMPDEST analysis of `bytecode`, the code of the
+    /// root call, matches the execution: every JUMP, and every JUMPI whose
+    /// condition is non zero, of the root call must land on one of
+    /// [`Bytecode::jumpdests`].  The first jump which doesn't results in an
+    /// [`Error::InvalidJumpDestination`].
+    pub fn validate_jumpdests(&self, bytecode: &Bytecode) -> Result<(), Error> {
+        let root_depth = match self.struct_logs.first() {
+            Some(step) => step.depth,
+            None => return Ok(()),
+        };
+        let jumpdests = bytecode.jumpdests();
+        for step in self.struct_logs.iter().filter(|s| s.depth == root_depth) {
+            let dest = match step.op {
+                OpcodeId::JUMP => step.stack.last()?,
+                OpcodeId::JUMPI if !step.stack.nth_last(1)?.is_zero() => step.stack.last()?,
+                _ => continue,
+            };
+            if dest > Word::from(usize::MAX) || !jumpdests.contains(&dest.as_usize()) {
+                return Err(Error::InvalidJumpDestination(step.pc.0, dest));
+            }
+        }
+        Ok(())
+    }
+}
+
 #[macro_export]
 /// Create an [`Address`] from a hex string.  Panics on invalid input.
 macro_rules! address {
@@ -583,6 +656,63 @@ mod tests {
             }
         );
     }
+
+    fn jump_trace(steps: Vec<(usize, OpcodeId, Vec<u64>)>) -> GethExecTrace {
+        GethExecTrace {
+            gas: Gas(0),
+            failed: false,
+            return_value: "".to_owned(),
+            struct_logs: steps
+                .into_iter()
+                .map(|(pc, op, stack)| GethExecStep {
+                    pc: ProgramCounter(pc),
+                    op,
+                    gas: Gas(0),
+                    refund: Gas(0),
+                    gas_cost: GasCost(0),
+                    depth: 1,
+                    error: None,
+                    stack: Stack(stack.into_iter().map(Word::from).collect()),
+                    storage: Storage(word_map!()),
+                    memory: Memory::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn validate_jumpdests() {
+        // 0: PUSH1 6, 2: JUMP, 3: PUSH2 0x5b5b, 6: JUMPDEST, 7: STOP
+        let code = Bytecode::from(vec![0x60, 0x06, 0x56, 0x61, 0x5b, 0x5b, 0x5b, 0x00]);
+        assert_eq!(code.jumpdests(), std::collections::HashSet::from([6]));
+
+        let trace = jump_trace(vec![
+            (0, OpcodeId::PUSH1, vec![]),
+            (2, OpcodeId::JUMP, vec![6]),
+            (6, OpcodeId::JUMPDEST, vec![]),
+            (7, OpcodeId::STOP, vec![]),
+        ]);
+        assert_eq!(trace.validate_jumpdests(&code), Ok(()));
+
+        // A jump into the push data, on a JUMPDEST byte.
+        let trace = jump_trace(vec![
+            (0, OpcodeId::PUSH1, vec![]),
+            (2, OpcodeId::JUMP, vec![4]),
+        ]);
+        assert_eq!(
+            trace.validate_jumpdests(&code),
+            Err(Error::InvalidJumpDestination(2, Word::from(4)))
+        );
+
+        // A JUMPI which isn't taken doesn't need a valid destination.
+        let trace = jump_trace(vec![(2, OpcodeId::JUMPI, vec![0, 4])]);
+        assert_eq!(trace.validate_jumpdests(&code), Ok(()));
+        let trace = jump_trace(vec![(2, OpcodeId::JUMPI, vec![1, 5])]);
+        assert_eq!(
+            trace.validate_jumpdests(&code),
+            Err(Error::InvalidJumpDestination(2, Word::from(5)))
+        );
+    }
 }
 
 #[cfg(test)]
@@ -673,4 +803,42 @@ mod eth_types_test {
         assert_eq!(word_from_u128, word_from_str);
         Ok(())
     }
+
+    #[test]
+    fn word_bits() {
+        let word = Word::one() | (Word::one() << 255);
+        assert_eq!(word.checked_bit(0), Ok(true));
+        assert_eq!(word.checked_bit(1), Ok(false));
+        assert_eq!(word.checked_bit(255), Ok(true));
+        assert!(word.msb());
+        assert!(!Word::from(u128::MAX).msb());
+
+        assert_eq!(word.checked_bit(256), Err(Error::BitIndexOutOfRange(256)));
+    }
+
+    #[test]
+    fn word_u64_limbs() {
+        let word = word!("0x0123456789abcdeffedcba987654321000112233445566778899aabbccddeeff");
+        let limbs = word.to_u64_limbs();
+        assert_eq!(
+            limbs,
+            [
+                0x8899aabbccddeeff,
+                0x0011223344556677,
+                0xfedcba9876543210,
+                0x0123456789abcdef
+            ]
+        );
+        assert_eq!(Word::from_u64_limbs(limbs), word);
+        assert_eq!(Word::from_u64_limbs([1, 0, 0, 0]), Word::one());
+        assert_eq!(Word::MAX.to_u64_limbs(), [u64::MAX; 4]);
+
+        // The limb at index i is made of the bytes 8 * i..8 * (i + 1) of the
+        // little endian bytes of the word.
+        let bytes = word.to_le_bytes();
+        for (idx, limb) in limbs.iter().enumerate() {
+            let limb_bytes = bytes[8 * idx..8 * (idx + 1)].try_into().unwrap();
+            assert_eq!(*limb, u64::from_le_bytes(limb_bytes));
+        }
+    }
 }
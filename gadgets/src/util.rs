@@ -1,7 +1,7 @@
 //! Utility traits, functions used in the crate.
 use eth_types::{
     evm_types::{GasCost, OpcodeId},
-    U256,
+    WordLimbs, U256,
 };
 use halo2_proofs::{arithmetic::FieldExt, plonk::Expression};
 
@@ -217,18 +217,14 @@ pub fn pow_of_two<F: FieldExt>(by: usize) -> F {
 
 /// Returns tuple consists of low and high part of U256
 pub fn split_u256(value: &U256) -> (U256, U256) {
+    let [limb0, limb1, limb2, limb3] = value.to_u64_limbs();
     (
-        U256([value.0[0], value.0[1], 0, 0]),
-        U256([value.0[2], value.0[3], 0, 0]),
+        U256::from_u64_limbs([limb0, limb1, 0, 0]),
+        U256::from_u64_limbs([limb2, limb3, 0, 0]),
     )
 }
 
 /// Split a U256 value into 4 64-bit limbs stored in U256 values.
 pub fn split_u256_limb64(value: &U256) -> [U256; 4] {
-    [
-        U256([value.0[0], 0, 0, 0]),
-        U256([value.0[1], 0, 0, 0]),
-        U256([value.0[2], 0, 0, 0]),
-        U256([value.0[3], 0, 0, 0]),
-    ]
+    value.to_u64_limbs().map(U256::from)
 }
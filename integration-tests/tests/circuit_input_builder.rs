@@ -4,6 +4,7 @@ use bus_mapping::circuit_input_builder::{BuilderClient, CircuitsParams};
 use integration_tests::{get_client, log_init, GenDataOutput};
 use lazy_static::lazy_static;
 use log::trace;
+use std::time::{Duration, Instant};
 
 lazy_static! {
     pub static ref GEN_DATA: GenDataOutput = GenDataOutput::load();
@@ -55,6 +56,37 @@ async fn test_circuit_input_builder_block(block_num: u64) {
     trace!("CircuitInputBuilder: {:#?}", builder);
 }
 
+#[tokio::test]
+async fn test_circuit_input_builder_profile() {
+    log_init();
+    let block_num = GEN_DATA.blocks.get("Transfer 0").unwrap();
+    let cli = BuilderClient::new(get_client(), CircuitsParams::default())
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    let (_, _, profile) = cli.gen_inputs_with_profile(*block_num).await.unwrap();
+    let elapsed = start.elapsed();
+    trace!("GenInputsProfile: {:#?}", profile);
+
+    let phases = profile.phases();
+    assert_eq!(
+        phases.map(|(name, _)| name),
+        [
+            "get_block",
+            "get_state_accesses",
+            "get_state",
+            "build_state_code_db",
+            "gen_inputs_from_state"
+        ]
+    );
+    // Querying geth always takes some time.
+    assert!(!profile.get_block.is_zero());
+    assert!(!profile.get_state.is_zero());
+    let total: Duration = phases.into_iter().map(|(_, duration)| duration).sum();
+    assert!(total <= elapsed, "{:?} > {:?}", total, elapsed);
+}
+
 macro_rules! declare_tests {
     ($test_name:ident, $block_tag:expr) => {
         #[tokio::test]
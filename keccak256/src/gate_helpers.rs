@@ -1,19 +1,28 @@
 use eth_types::Field;
 use num_bigint::BigUint;
 
+/// Error returned when converting a bigUint value which isn't smaller than
+/// the field size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldOverflow(pub BigUint);
+
 /// Convert a bigUint value to FieldExt
 ///
 /// We assume the input value is smaller than the field size
 pub fn biguint_to_f<F: Field>(x: &BigUint) -> F {
+    biguint_to_f_checked(x).expect("value exceeds the field size")
+}
+
+/// Convert a bigUint value to FieldExt, failing with [`FieldOverflow`]
+/// instead of reducing the value when it isn't smaller than the field size
+pub fn biguint_to_f_checked<F: Field>(x: &BigUint) -> Result<F, FieldOverflow> {
     let mut x_bytes = x.to_bytes_le();
-    debug_assert!(
-        x_bytes.len() <= 32,
-        "expect len <=32 but got {}",
-        x_bytes.len()
-    );
+    if x_bytes.len() > 32 {
+        return Err(FieldOverflow(x.clone()));
+    }
     x_bytes.resize(32, 0);
     let x_bytes: [u8; 32] = x_bytes.try_into().unwrap();
-    F::from_repr_vartime(x_bytes).unwrap()
+    F::from_repr_vartime(x_bytes).ok_or_else(|| FieldOverflow(x.clone()))
 }
 
 pub fn f_to_biguint<F: Field>(x: F) -> BigUint {
@@ -26,3 +35,23 @@ pub fn biguint_mod(x: &BigUint, modulus: u8) -> u8 {
         .copied()
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::halo2curves::bn256::Fr;
+
+    #[test]
+    fn biguint_to_f_overflow() {
+        let max = f_to_biguint(-Fr::one());
+        assert_eq!(biguint_to_f_checked::<Fr>(&max), Ok(-Fr::one()));
+        assert_eq!(f_to_biguint(biguint_to_f::<Fr>(&max)), max);
+
+        let modulus = &max + 1u8;
+        let result = biguint_to_f_checked::<Fr>(&modulus);
+        assert_eq!(result, Err(FieldOverflow(modulus)));
+        let too_long = BigUint::from(1u8) << 256;
+        let result = biguint_to_f_checked::<Fr>(&too_long);
+        assert_eq!(result, Err(FieldOverflow(too_long)));
+    }
+}
@@ -6,6 +6,7 @@ pub mod common;
 pub mod gate_helpers;
 // We build arith module to get test cases for the circuit
 pub mod keccak_arith;
+pub mod permutation;
 // We build plain module for the purpose of reviewing the circuit
 pub mod plain;
 
@@ -0,0 +1,446 @@
+//! Gates for the steps of the Keccak-f permutation, on a state whose 25 lanes
+//! are each held in a cell as a sparse base-13 or base-9 number.
+
+pub mod absorb;
+pub mod chi;
+pub mod from_base9;
+pub mod iota;
+pub mod rho;
+pub mod tables;
+pub mod theta;
+
+use crate::arith_helpers::{convert_b9_lane_to_b13, StateBigInt};
+use crate::common::{State, PERMUTATION, ROUND_CONSTANTS};
+use crate::gate_helpers::{biguint_to_f, f_to_biguint};
+use crate::keccak_arith::KeccakFArith;
+use absorb::{block_input_len, pad_input, AbsorbConfig, InputWords};
+use chi::ChiConfig;
+use eth_types::Field;
+use from_base9::FromBase9Config;
+#[cfg(test)]
+use halo2_proofs::circuit::Value;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, VirtualCells},
+    poly::Rotation,
+};
+use iota::IotaConfig;
+use itertools::Itertools;
+use rho::RhoConfig;
+use theta::ThetaConfig;
+
+/// Config of the Keccak-f permutation, which runs its 24 rounds on a base-13
+/// state by chaining the theta, rho, pi, chi and iota steps, as
+/// [`KeccakFArith::permute_and_absorb`] does, and converts the base-9 state
+/// out of each round back to base 13. Pi only moves lanes around, so it is a
+/// reordering of the cells copied into chi. When a next input block is
+/// absorbed, the last round absorbs it after chi and adds the round constant
+/// in base 13 after the conversion.
+#[derive(Clone, Debug)]
+pub struct KeccakFConfig<F> {
+    state: [Column<Advice>; 25],
+    theta: ThetaConfig<F>,
+    rho: RhoConfig<F>,
+    chi: ChiConfig<F>,
+    iota: IotaConfig<F>,
+    absorb: AbsorbConfig<F>,
+    from_base9: FromBase9Config<F>,
+}
+
+impl<F: Field> KeccakFConfig<F> {
+    /// Configure the steps of the permutation, where theta, chi, iota and
+    /// absorb share their 25 state columns.
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
+        let state = [(); 25].map(|_| meta.advice_column());
+        Self {
+            state,
+            theta: ThetaConfig::configure(meta, state),
+            rho: RhoConfig::configure(meta),
+            chi: ChiConfig::configure(meta, state),
+            iota: IotaConfig::configure(meta, state),
+            absorb: AbsorbConfig::configure(meta, state),
+            from_base9: FromBase9Config::configure(meta),
+        }
+    }
+
+    /// Load the lookup tables of the base conversions.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.rho.load(layouter)?;
+        self.from_base9.load(layouter)
+    }
+
+    /// Permute `state`, whose lanes are the bits of `state_values` in base 13,
+    /// and absorb `next_input` into it if any, as
+    /// [`KeccakFArith::permute_and_absorb`] does. The next input comes with
+    /// the number of its bytes taken from the input, as in
+    /// [`AbsorbConfig::assign_state`]. Returns the cells of the resulting
+    /// state, in base 13, and of the words of the next input if any.
+    pub fn assign_permutation(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        state_values: [F; 25],
+        next_input: Option<(&State, usize)>,
+    ) -> Result<([AssignedCell<F, F>; 25], Option<InputWords<F>>), Error> {
+        let mut state = state.clone();
+        let mut state_values = state_to_biguint(&state_values);
+        let mut words = None;
+        for (round, rc) in ROUND_CONSTANTS.iter().enumerate() {
+            let theta_values = KeccakFArith::theta(&state_values);
+            let rho_values = KeccakFArith::rho(&theta_values);
+            let chi_values = KeccakFArith::xi(&KeccakFArith::pi(&rho_values));
+
+            let theta_state = state_to_f(&theta_values);
+            state = self.theta.assign_state(layouter, &state, theta_state)?;
+            state = self.rho.assign_state(layouter, &state, theta_state)?;
+            state = self
+                .chi
+                .assign_state(layouter, &pi(&state), state_to_f(&chi_values))?;
+
+            match next_input.filter(|_| round == PERMUTATION - 1) {
+                Some((next_input, input_len)) => {
+                    let absorb_values = KeccakFArith::absorb(&chi_values, next_input);
+                    let (absorb_state, absorb_words) = self.absorb.assign_state(
+                        layouter,
+                        &state,
+                        state_to_f(&absorb_values),
+                        next_input,
+                        input_len,
+                    )?;
+                    state = absorb_state;
+                    words = Some(absorb_words);
+                    state = self.from_base9.assign_state(
+                        layouter,
+                        &state,
+                        state_to_f(&absorb_values),
+                    )?;
+
+                    state_values = KeccakFArith::iota_b13(
+                        &StateBigInt::from_state_big_int(&absorb_values, convert_b9_lane_to_b13),
+                        *rc,
+                    );
+                    state = self.iota.assign_state_b13(
+                        layouter,
+                        &state,
+                        state_to_f(&state_values),
+                        *rc,
+                    )?;
+                }
+                None => {
+                    let iota_values = KeccakFArith::iota_b9(&chi_values, *rc);
+                    state =
+                        self.iota
+                            .assign_state(layouter, &state, state_to_f(&iota_values), *rc)?;
+                    state =
+                        self.from_base9
+                            .assign_state(layouter, &state, state_to_f(&iota_values))?;
+
+                    state_values =
+                        StateBigInt::from_state_big_int(&iota_values, convert_b9_lane_to_b13);
+                }
+            }
+        }
+        Ok((state, words))
+    }
+
+    /// Absorb `input`, padded into blocks by [`pad_input`], with a
+    /// permutation per block. The first block is absorbed into the all-zero
+    /// state, made of constants, and converted to base 13, and each next block
+    /// is absorbed by the permutation of the previous one. Returns the cells
+    /// of the state after the last permutation, in base 13, whose lanes
+    /// `(0, 0)` to `(3, 0)` hold the hash, and of the words of each block.
+    pub fn assign_input(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[u8],
+    ) -> Result<([AssignedCell<F, F>; 25], Vec<InputWords<F>>), Error> {
+        let blocks = pad_input(input);
+        let zero_state = layouter.assign_region(
+            || "zero state",
+            |mut region| {
+                let state = self
+                    .state
+                    .iter()
+                    .map(|column| {
+                        region.assign_advice_from_constant(|| "zero lane", *column, 0, F::zero())
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(state.try_into().unwrap())
+            },
+        )?;
+        let absorb_values = KeccakFArith::absorb(&StateBigInt::default(), &blocks[0]);
+        let (state, first_words) = self.absorb.assign_state(
+            layouter,
+            &zero_state,
+            state_to_f(&absorb_values),
+            &blocks[0],
+            block_input_len(input.len(), 0),
+        )?;
+        let mut state =
+            self.from_base9
+                .assign_state(layouter, &state, state_to_f(&absorb_values))?;
+        let mut state_values =
+            StateBigInt::from_state_big_int(&absorb_values, convert_b9_lane_to_b13);
+
+        let mut words = vec![first_words];
+        for (block_idx, next_input) in blocks.iter().enumerate().skip(1) {
+            let (next_state, next_words) = self.assign_permutation(
+                layouter,
+                &state,
+                state_to_f(&state_values),
+                Some((next_input, block_input_len(input.len(), block_idx))),
+            )?;
+            state = next_state;
+            words.extend(next_words);
+            KeccakFArith::permute_and_absorb(&mut state_values, Some(next_input));
+        }
+        let (state, _) =
+            self.assign_permutation(layouter, &state, state_to_f(&state_values), None)?;
+        Ok((state, words))
+    }
+}
+
+/// Move the lane `(x, y)` of `state` to `(y, 2 * x + 3 * y)`.
+fn pi<T: Clone>(state: &[T; 25]) -> [T; 25] {
+    let mut out = state.clone();
+    for (x, y) in (0..5).cartesian_product(0..5) {
+        out[5 * y + (2 * x + 3 * y) % 5] = state[5 * x + y].clone();
+    }
+    out
+}
+
+/// `base` to the power `exp`.
+pub(crate) fn base_power<F: Field>(base: u8, exp: usize) -> F {
+    (0..exp).fold(F::one(), |acc, _| acc * F::from(base as u64))
+}
+
+/// The number in `base` whose digits, from the least significant one, are on
+/// the current row of `columns`.
+pub(crate) fn compose<F: Field>(
+    meta: &mut VirtualCells<'_, F>,
+    columns: &[Column<Advice>],
+    base: u8,
+) -> Expression<F> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| {
+            meta.query_advice(*column, Rotation::cur())
+                * Expression::Constant(base_power::<F>(base, idx))
+        })
+        .reduce(|acc, digit| acc + digit)
+        .unwrap()
+}
+
+/// The lanes of `state` as field elements, in the layout of the state columns
+/// of the gates.
+pub(crate) fn state_to_f<F: Field>(state: &StateBigInt) -> [F; 25] {
+    let mut out = [F::zero(); 25];
+    for (x, y) in (0..5).cartesian_product(0..5) {
+        out[5 * x + y] = biguint_to_f(&state[(x, y)]);
+    }
+    out
+}
+
+/// Assign the lanes of `state` on the first row of a new region, in the state
+/// `columns` of a gate under test.
+#[cfg(test)]
+pub(crate) fn load_state<F: Field>(
+    layouter: &mut impl Layouter<F>,
+    columns: &[Column<Advice>; 25],
+    state: &[F; 25],
+) -> Result<[AssignedCell<F, F>; 25], Error> {
+    layouter.assign_region(
+        || "load state",
+        |mut region| {
+            let state = state
+                .iter()
+                .zip(columns.iter())
+                .map(|(lane, column)| {
+                    region.assign_advice(|| "lane", *column, 0, || Value::known(*lane))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            Ok(state.try_into().unwrap())
+        },
+    )
+}
+
+fn state_to_biguint<F: Field>(state: &[F; 25]) -> StateBigInt {
+    let mut out = StateBigInt::default();
+    for (x, y) in (0..5).cartesian_product(0..5) {
+        out[(x, y)] = f_to_biguint(state[5 * x + y]);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arith_helpers::convert_b2_to_b13;
+    use crate::plain::Keccak;
+    use halo2_proofs::circuit::SimpleFloorPlanner;
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use halo2_proofs::plonk::Circuit;
+    use pretty_assertions::assert_eq;
+
+    struct MyCircuit<F> {
+        in_state: [F; 25],
+        out_state: [F; 25],
+    }
+
+    impl<F: Field> Circuit<F> for MyCircuit<F> {
+        type Config = KeccakFConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                in_state: [F::zero(); 25],
+                out_state: [F::zero(); 25],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            KeccakFConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            config.load(&mut layouter)?;
+            let in_state = load_state(&mut layouter, &config.state, &self.in_state)?;
+            let (out_state, _) =
+                config.assign_permutation(&mut layouter, &in_state, self.in_state, None)?;
+            layouter.assign_region(
+                || "check state",
+                |mut region| {
+                    for ((lane, column), cell) in self
+                        .out_state
+                        .iter()
+                        .zip(config.state.iter())
+                        .zip(out_state.iter())
+                    {
+                        let expected =
+                            region.assign_advice(|| "lane", *column, 0, || Value::known(*lane))?;
+                        region.constrain_equal(expected.cell(), cell.cell())?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    struct InputCircuit<F> {
+        input: Vec<u8>,
+        // The lanes `(0, 0)` to `(3, 0)` of the hash, in base 13
+        hash: [F; 4],
+    }
+
+    impl<F: Field> Circuit<F> for InputCircuit<F> {
+        type Config = KeccakFConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                input: self.input.clone(),
+                hash: [F::zero(); 4],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            KeccakFConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            config.load(&mut layouter)?;
+            let (out_state, words) = config.assign_input(&mut layouter, &self.input)?;
+            assert_eq!(words.len(), pad_input(&self.input).len());
+            layouter.assign_region(
+                || "check hash",
+                |mut region| {
+                    for (x, lane) in self.hash.iter().enumerate() {
+                        let expected = region.assign_advice(
+                            || "hash lane",
+                            config.state[5 * x],
+                            0,
+                            || Value::known(*lane),
+                        )?;
+                        region.constrain_equal(expected.cell(), out_state[5 * x].cell())?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_keccak_input() {
+        for len in [0, 135, 136, 200] {
+            let input = (0..len).map(|idx| idx as u8).collect::<Vec<_>>();
+            let mut keccak = Keccak::default();
+            keccak.update(&input);
+            let hash = keccak
+                .digest()
+                .chunks(8)
+                .map(|word| {
+                    let word = u64::from_le_bytes(word.try_into().unwrap());
+                    biguint_to_f::<Fr>(&convert_b2_to_b13(word))
+                })
+                .collect::<Vec<_>>();
+
+            let circuit = InputCircuit::<Fr> {
+                input: input.clone(),
+                hash: hash.try_into().unwrap(),
+            };
+            let prover = MockProver::<Fr>::run(12, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify(), Ok(()), "input of {} bytes", len);
+
+            // A hash whose last lane is off by one isn't accepted.
+            let mut hash = circuit.hash;
+            hash[3] += Fr::one();
+            let circuit = InputCircuit::<Fr> { input, hash };
+            let prover = MockProver::<Fr>::run(12, &circuit, vec![]).unwrap();
+            assert!(prover.verify().is_err(), "input of {} bytes", len);
+        }
+    }
+
+    #[test]
+    fn test_keccak_f() {
+        let input: State = [
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 9223372036854775808, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0xffff_ffff_ffff_ffff, 0, 0x0123_4567_89ab_cdef],
+        ];
+        let mut in_biguint = StateBigInt::default();
+        for (x, y) in (0..5).cartesian_product(0..5) {
+            in_biguint[(x, y)] = convert_b2_to_b13(input[x][y]);
+        }
+        let in_state = state_to_f(&in_biguint);
+        // The last round of the arithmetic permutation stays in base 9.
+        KeccakFArith::permute_and_absorb(&mut in_biguint, None);
+        let out_biguint = StateBigInt::from_state_big_int(&in_biguint, convert_b9_lane_to_b13);
+
+        let circuit = MyCircuit::<Fr> {
+            in_state,
+            out_state: state_to_f(&out_biguint),
+        };
+        let prover = MockProver::<Fr>::run(11, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // The input state isn't its own permutation.
+        let circuit = MyCircuit::<Fr> {
+            in_state,
+            out_state: in_state,
+        };
+        let prover = MockProver::<Fr>::run(11, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,266 @@
+use super::compose;
+use crate::arith_helpers::{convert_b2_to_b9, A4, B9};
+use crate::common::{State, NEXT_INPUTS_LANES};
+use crate::gate_helpers::biguint_to_f;
+use eth_types::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+use itertools::Itertools;
+use std::{iter::once, marker::PhantomData};
+
+/// Number of bytes of the input absorbed by each permutation.
+pub const RATE: usize = NEXT_INPUTS_LANES * 8;
+
+/// Pad `input` with the `pad10*1` rule into blocks of [`RATE`] bytes, and
+/// return the lanes of each block, where the word `i` of the block is the lane
+/// `(i % 5, i / 5)` and the lanes past the rate are zero. An input whose
+/// length is a multiple of [`RATE`] is followed by a whole block of padding.
+pub fn pad_input(input: &[u8]) -> Vec<State> {
+    let mut padded = input.to_vec();
+    padded.push(0x01);
+    padded.resize((input.len() / RATE + 1) * RATE, 0x00);
+    *padded.last_mut().unwrap() |= 0x80;
+
+    padded
+        .chunks(RATE)
+        .map(|block| {
+            let mut lanes = State::default();
+            for (idx, word) in block.chunks(8).enumerate() {
+                lanes[idx % 5][idx / 5] = u64::from_le_bytes(word.try_into().unwrap());
+            }
+            lanes
+        })
+        .collect()
+}
+
+/// The cells of the words of an input block, in base 9.
+pub type InputWords<F> = [AssignedCell<F, F>; NEXT_INPUTS_LANES];
+
+/// Number of the bytes of the block `block_idx` of a padded input of
+/// `input_len` bytes that are taken from the input, the others being padding.
+pub fn block_input_len(input_len: usize, block_idx: usize) -> usize {
+    input_len.saturating_sub(block_idx * RATE).min(RATE)
+}
+
+/// Config of the absorb step on a base-9 state out of chi, whose lane `(x, y)`
+/// is in the column `5 * x + y`. The words of the next input block, in base 9,
+/// are added `A4` times to their lanes of the state on the enabled row to give
+/// the state on the next row, as in
+/// [`KeccakFArith::absorb`](crate::keccak_arith::KeccakFArith::absorb), and
+/// the lanes past the rate are kept. Each input bit then takes the place of
+/// the `x4` operand of the base-9 digit map, which XORs it into the state.
+///
+/// The word `i` of the block is copied onto the row `i` of the region and
+/// decomposed into its 64 bits, which bounds it to a base-9 lane. The bits of
+/// the padding are fixed by constants.
+#[derive(Clone, Debug)]
+pub struct AbsorbConfig<F> {
+    q_enable: Selector,
+    q_input: Selector,
+    state: [Column<Advice>; 25],
+    // The word `i` of the block, added to the lane `(i % 5, i / 5)`
+    next_input: [Column<Advice>; NEXT_INPUTS_LANES],
+    input_word: Column<Advice>,
+    input_bits: [Column<Advice>; 64],
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> AbsorbConfig<F> {
+    /// Configure the absorb gate over the 25 `state` columns, which are
+    /// enabled for equality so that a state can be copied in, and columns of
+    /// its own for the input block and its bits, along with a fixed column for
+    /// the constants.
+    pub fn configure(meta: &mut ConstraintSystem<F>, state: [Column<Advice>; 25]) -> Self {
+        let q_enable = meta.selector();
+        let q_input = meta.selector();
+        let next_input = [(); NEXT_INPUTS_LANES].map(|_| meta.advice_column());
+        let input_word = meta.advice_column();
+        let input_bits = [(); 64].map(|_| meta.advice_column());
+        let constants = meta.fixed_column();
+        meta.enable_constant(constants);
+        for column in state
+            .iter()
+            .chain(next_input.iter())
+            .chain(once(&input_word))
+            .chain(input_bits.iter())
+        {
+            meta.enable_equality(*column);
+        }
+
+        meta.create_gate("absorb", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            (0..5)
+                .cartesian_product(0..5)
+                .map(|(x, y)| {
+                    let column = state[5 * x + y];
+                    let lane = meta.query_advice(column, Rotation::cur());
+                    let new_lane = meta.query_advice(column, Rotation::next());
+                    let added = match next_input.get(x + 5 * y) {
+                        Some(input_column) => {
+                            Expression::Constant(F::from(A4))
+                                * meta.query_advice(*input_column, Rotation::cur())
+                        }
+                        None => Expression::Constant(F::zero()),
+                    };
+                    q_enable.clone() * (new_lane - lane - added)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        meta.create_gate("absorb input bits", |meta| {
+            let q_input = meta.query_selector(q_input);
+            let word = meta.query_advice(input_word, Rotation::cur());
+            let bits_sum = compose(meta, &input_bits, B9);
+            once(q_input.clone() * (word - bits_sum))
+                .chain(input_bits.iter().map(|column| {
+                    let bit = meta.query_advice(*column, Rotation::cur());
+                    q_input.clone() * bit.clone() * (Expression::Constant(F::one()) - bit)
+                }))
+                .collect::<Vec<_>>()
+        });
+
+        Self {
+            q_enable,
+            q_input,
+            state,
+            next_input,
+            input_word,
+            input_bits,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Copy `state` into a new region, assign the words of `next_input` in
+    /// base 9 beside it and `out_state`, the absorb of `next_input` into its
+    /// values, on the next row. The first `input_len` bytes of `next_input`
+    /// are taken from the input and the others are padding. Returns the
+    /// cells of `out_state` and of the words of `next_input`.
+    pub fn assign_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        out_state: [F; 25],
+        next_input: &State,
+        input_len: usize,
+    ) -> Result<([AssignedCell<F, F>; 25], InputWords<F>), Error> {
+        layouter.assign_region(
+            || "absorb",
+            |mut region| {
+                let offset = 0;
+                self.q_enable.enable(&mut region, offset)?;
+                for (lane, column) in state.iter().zip(self.state.iter()) {
+                    lane.copy_advice(|| "absorb input", &mut region, *column, offset)?;
+                }
+                let words = self
+                    .next_input
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, column)| {
+                        let word = next_input[idx % 5][idx / 5];
+                        let cell = region.assign_advice(
+                            || "next input word",
+                            *column,
+                            offset,
+                            || Value::known(biguint_to_f::<F>(&convert_b2_to_b9(word))),
+                        )?;
+                        self.assign_input_bits(&mut region, idx, &cell, word, input_len)?;
+                        Ok(cell)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                let out_state = out_state
+                    .iter()
+                    .zip(self.state.iter())
+                    .map(|(lane, column)| {
+                        region.assign_advice(
+                            || "absorb output",
+                            *column,
+                            offset + 1,
+                            || Value::known(*lane),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok((out_state.try_into().unwrap(), words.try_into().unwrap()))
+            },
+        )
+    }
+
+    /// Copy the cell of the word `idx` of the block onto the row `idx` and
+    /// assign the bits of its value `word`, where the bits past the first
+    /// `input_len` bytes of the block are padding and fixed to constants.
+    fn assign_input_bits(
+        &self,
+        region: &mut Region<'_, F>,
+        idx: usize,
+        cell: &AssignedCell<F, F>,
+        word: u64,
+        input_len: usize,
+    ) -> Result<(), Error> {
+        self.q_input.enable(region, idx)?;
+        cell.copy_advice(|| "next input word", region, self.input_word, idx)?;
+        for (bit_idx, column) in self.input_bits.iter().enumerate() {
+            let bit = F::from((word >> bit_idx) & 1);
+            if 8 * idx + bit_idx / 8 < input_len {
+                region.assign_advice(|| "input bit", *column, idx, || Value::known(bit))?;
+            } else {
+                region.assign_advice_from_constant(|| "padding bit", *column, idx, bit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_pad_input() {
+        for (len, n_blocks) in [(0, 1), (135, 1), (136, 2), (200, 2)] {
+            let input = vec![0xff; len];
+            let blocks = pad_input(&input);
+            assert_eq!(blocks.len(), n_blocks);
+
+            // The input is followed by a 1 bit, then zeros up to the last bit
+            // of the last block.
+            let padded = blocks
+                .iter()
+                .flat_map(|lanes| {
+                    (0..NEXT_INPUTS_LANES).flat_map(|idx| lanes[idx % 5][idx / 5].to_le_bytes())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(padded.len(), n_blocks * RATE);
+            assert_eq!(padded[..len], input[..]);
+            let mut padding = vec![0x00; n_blocks * RATE - len];
+            padding[0] |= 0x01;
+            *padding.last_mut().unwrap() |= 0x80;
+            assert_eq!(padded[len..], padding[..]);
+
+            // The lanes past the rate are zero.
+            for lanes in blocks.iter() {
+                for idx in NEXT_INPUTS_LANES..25 {
+                    assert_eq!(lanes[idx % 5][idx / 5], 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_input_len() {
+        for (len, input_lens) in [
+            (0, vec![0]),
+            (135, vec![135]),
+            (136, vec![136, 0]),
+            (200, vec![136, 64]),
+        ] {
+            let blocks = pad_input(&vec![0xff; len]);
+            let block_input_lens = (0..blocks.len())
+                .map(|block_idx| block_input_len(len, block_idx))
+                .collect::<Vec<_>>();
+            assert_eq!(block_input_lens, input_lens);
+        }
+    }
+}
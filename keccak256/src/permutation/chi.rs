@@ -0,0 +1,173 @@
+use crate::arith_helpers::{A1, A2, A3};
+use eth_types::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+use itertools::Itertools;
+use std::marker::PhantomData;
+
+/// Config of the chi step on a base-9 state, whose lane `(x, y)` is in the
+/// column `5 * x + y`. The state on the enabled row is mixed into the state on
+/// the next row as `a'[x][y] = 2 * a[x][y] + a[x + 1][y] + 3 * a[x + 2][y]`.
+/// Each base-9 digit of the output is below 9 and is mapped to the bit
+/// `a ^ (!b & c)` by the base-9 conversion that follows, see
+/// [`convert_b9_coef`](crate::arith_helpers::convert_b9_coef).
+#[derive(Clone, Debug)]
+pub struct ChiConfig<F> {
+    q_enable: Selector,
+    state: [Column<Advice>; 25],
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> ChiConfig<F> {
+    /// Configure the chi gate over the 25 `state` columns, which are enabled
+    /// for equality so that a state can be copied in.
+    pub fn configure(meta: &mut ConstraintSystem<F>, state: [Column<Advice>; 25]) -> Self {
+        let q_enable = meta.selector();
+        for column in state.iter() {
+            meta.enable_equality(*column);
+        }
+
+        meta.create_gate("chi", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let lanes: Vec<Expression<F>> = state
+                .iter()
+                .map(|column| meta.query_advice(*column, Rotation::cur()))
+                .collect();
+
+            (0..5)
+                .cartesian_product(0..5)
+                .map(|(x, y)| {
+                    let lane = |x: usize| lanes[5 * (x % 5) + y].clone();
+                    let new_lane = meta.query_advice(state[5 * x + y], Rotation::next());
+                    let mixed = Expression::Constant(F::from(A1)) * lane(x)
+                        + Expression::Constant(F::from(A2)) * lane(x + 1)
+                        + Expression::Constant(F::from(A3)) * lane(x + 2);
+                    q_enable.clone() * (new_lane - mixed)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Self {
+            q_enable,
+            state,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Copy `state` into a new region and assign `out_state`, the chi of its
+    /// values, on the next row. Returns the cells of `out_state`.
+    pub fn assign_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        out_state: [F; 25],
+    ) -> Result<[AssignedCell<F, F>; 25], Error> {
+        layouter.assign_region(
+            || "chi",
+            |mut region| {
+                let offset = 0;
+                self.q_enable.enable(&mut region, offset)?;
+                for (lane, column) in state.iter().zip(self.state.iter()) {
+                    lane.copy_advice(|| "chi input", &mut region, *column, offset)?;
+                }
+                let out_state = out_state
+                    .iter()
+                    .zip(self.state.iter())
+                    .map(|(lane, column)| {
+                        region.assign_advice(
+                            || "chi output",
+                            *column,
+                            offset + 1,
+                            || Value::known(*lane),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(out_state.try_into().unwrap())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arith_helpers::{convert_b2_to_b9, StateBigInt};
+    use crate::common::State;
+    use crate::keccak_arith::KeccakFArith;
+    use crate::permutation::{load_state, state_to_f};
+    use halo2_proofs::circuit::SimpleFloorPlanner;
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use halo2_proofs::plonk::Circuit;
+    use pretty_assertions::assert_eq;
+
+    struct MyCircuit<F> {
+        in_state: [F; 25],
+        out_state: [F; 25],
+    }
+
+    impl<F: Field> Circuit<F> for MyCircuit<F> {
+        type Config = ChiConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                in_state: [F::zero(); 25],
+                out_state: [F::zero(); 25],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let state: [Column<Advice>; 25] = (0..25)
+                .map(|_| meta.advice_column())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            ChiConfig::configure(meta, state)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let in_state = load_state(&mut layouter, &config.state, &self.in_state)?;
+            config.assign_state(&mut layouter, &in_state, self.out_state)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_chi_gate() {
+        let input: State = [
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 9223372036854775808, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0xffff_ffff_ffff_ffff, 0, 0x0123_4567_89ab_cdef],
+        ];
+        let mut in_biguint = StateBigInt::default();
+        for (x, y) in (0..5).cartesian_product(0..5) {
+            in_biguint[(x, y)] = convert_b2_to_b9(input[x][y]);
+        }
+        let out_biguint = KeccakFArith::xi(&in_biguint);
+
+        let circuit = MyCircuit::<Fr> {
+            in_state: state_to_f(&in_biguint),
+            out_state: state_to_f(&out_biguint),
+        };
+        let prover = MockProver::<Fr>::run(5, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // The unmixed input isn't accepted as the output of chi.
+        let circuit = MyCircuit::<Fr> {
+            in_state: circuit.in_state,
+            out_state: circuit.in_state,
+        };
+        let prover = MockProver::<Fr>::run(5, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use super::compose;
+use super::tables::FromBase9TableConfig;
+use crate::arith_helpers::{convert_b9_coef, convert_b9_lane_to_b13, B9};
+use crate::gate_helpers::{biguint_to_f, f_to_biguint};
+use eth_types::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+/// Config of the conversion of a base-9 state out of iota back to base 13, as
+/// the input of the theta step of the next round. The lane `(x, y)` is
+/// decomposed on the row `5 * x + y` of a region into its 64 chunks, and each
+/// chunk `2 * a + b + 3 * c + 2 * d` is mapped to the bit `a ^ (!b & c) ^ d`
+/// by a lookup into a [`FromBase9TableConfig`].
+#[derive(Clone, Debug)]
+pub struct FromBase9Config<F> {
+    q_enable: Selector,
+    lane: Column<Advice>,
+    chunks: [Column<Advice>; 64],
+    bits: [Column<Advice>; 64],
+    out: Column<Advice>,
+    table: FromBase9TableConfig<F>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> FromBase9Config<F> {
+    /// Configure the conversion gate and lookups, in columns of their own.
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
+        let q_enable = meta.complex_selector();
+        let lane = meta.advice_column();
+        let chunks = [(); 64].map(|_| meta.advice_column());
+        let bits = [(); 64].map(|_| meta.advice_column());
+        let out = meta.advice_column();
+        let table = FromBase9TableConfig::configure(meta);
+        let info = table.get_base_info(false);
+        meta.enable_equality(lane);
+        meta.enable_equality(out);
+
+        meta.create_gate("from base 9", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let lane = meta.query_advice(lane, Rotation::cur());
+            let out = meta.query_advice(out, Rotation::cur());
+            let chunks_sum = compose(meta, &chunks, info.input_base);
+            let bits_sum = compose(meta, &bits, info.output_base);
+            vec![
+                q_enable.clone() * (lane - chunks_sum),
+                q_enable * (out - bits_sum),
+            ]
+        });
+
+        for (chunk, bit) in chunks.iter().zip(bits.iter()) {
+            meta.lookup("from base 9 chunk", |meta| {
+                let q_enable = meta.query_selector(q_enable);
+                let chunk = meta.query_advice(*chunk, Rotation::cur());
+                let bit = meta.query_advice(*bit, Rotation::cur());
+                vec![
+                    (q_enable.clone() * chunk, info.input_tc),
+                    (q_enable * bit, info.output_tc),
+                ]
+            });
+        }
+
+        Self {
+            q_enable,
+            lane,
+            chunks,
+            bits,
+            out,
+            table,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Load the table of the base-9 chunks and their bits.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.table.load(layouter)
+    }
+
+    /// Copy `state`, out of iota with the values `state_values`, into a new
+    /// region and assign it in base 13. Returns the cells of the converted
+    /// state.
+    pub fn assign_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        state_values: [F; 25],
+    ) -> Result<[AssignedCell<F, F>; 25], Error> {
+        layouter.assign_region(
+            || "from base 9",
+            |mut region| {
+                let mut out_state = Vec::with_capacity(25);
+                for (offset, (lane, value)) in state.iter().zip(state_values.iter()).enumerate() {
+                    self.q_enable.enable(&mut region, offset)?;
+                    lane.copy_advice(|| "from base 9 input", &mut region, self.lane, offset)?;
+
+                    let value = f_to_biguint(*value);
+                    let mut chunks = value.to_radix_le(B9.into());
+                    chunks.resize(64, 0);
+                    for ((chunk_column, bit_column), chunk) in
+                        self.chunks.iter().zip(self.bits.iter()).zip(chunks.iter())
+                    {
+                        region.assign_advice(
+                            || "base-9 chunk",
+                            *chunk_column,
+                            offset,
+                            || Value::known(F::from(*chunk as u64)),
+                        )?;
+                        region.assign_advice(
+                            || "bit",
+                            *bit_column,
+                            offset,
+                            || Value::known(F::from(convert_b9_coef(*chunk) as u64)),
+                        )?;
+                    }
+
+                    let out = convert_b9_lane_to_b13(value);
+                    out_state.push(region.assign_advice(
+                        || "from base 9 output",
+                        self.out,
+                        offset,
+                        || Value::known(biguint_to_f::<F>(&out)),
+                    )?);
+                }
+                Ok(out_state.try_into().unwrap())
+            },
+        )
+    }
+}
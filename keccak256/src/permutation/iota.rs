@@ -0,0 +1,216 @@
+use crate::arith_helpers::{convert_b2_to_b13, convert_b2_to_b9, A4};
+use crate::gate_helpers::biguint_to_f;
+use eth_types::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+/// Config of the iota step, whose state has the lane `(x, y)` in the column
+/// `5 * x + y`. The round constant in the fixed column is added to the lane
+/// `(0, 0)` of the state on the enabled row to give the state on the next row,
+/// where the other lanes are unchanged. On a base-9 state out of chi, the
+/// round constant is added `A4` times in base 9, and takes the place of the
+/// `x4` operand of the base-9 digit map. On a base-13 state out of an absorb,
+/// it is added once in base 13, as the next round then starts with theta.
+#[derive(Clone, Debug)]
+pub struct IotaConfig<F> {
+    q_enable: Selector,
+    state: [Column<Advice>; 25],
+    round_constant: Column<Fixed>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> IotaConfig<F> {
+    /// Configure the iota gate over the 25 `state` columns, which are enabled
+    /// for equality so that a state can be copied in.
+    pub fn configure(meta: &mut ConstraintSystem<F>, state: [Column<Advice>; 25]) -> Self {
+        let q_enable = meta.selector();
+        let round_constant = meta.fixed_column();
+        for column in state.iter() {
+            meta.enable_equality(*column);
+        }
+
+        meta.create_gate("iota", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let round_constant = meta.query_fixed(round_constant, Rotation::cur());
+            state
+                .iter()
+                .enumerate()
+                .map(|(idx, column)| {
+                    let lane = meta.query_advice(*column, Rotation::cur());
+                    let new_lane = meta.query_advice(*column, Rotation::next());
+                    let added = if idx == 0 {
+                        round_constant.clone()
+                    } else {
+                        Expression::Constant(F::zero())
+                    };
+                    q_enable.clone() * (new_lane - lane - added)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Self {
+            q_enable,
+            state,
+            round_constant,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Copy the base-9 `state` into a new region and assign `out_state`, the
+    /// iota of its values with the round constant `rc`, on the next row.
+    /// Returns the cells of `out_state`.
+    pub fn assign_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        out_state: [F; 25],
+        rc: u64,
+    ) -> Result<[AssignedCell<F, F>; 25], Error> {
+        let round_constant = F::from(A4) * biguint_to_f::<F>(&convert_b2_to_b9(rc));
+        self.assign(layouter, state, out_state, round_constant)
+    }
+
+    /// Copy the base-13 `state` into a new region and assign `out_state`, the
+    /// iota of its values with the round constant `rc`, on the next row.
+    /// Returns the cells of `out_state`.
+    pub fn assign_state_b13(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        out_state: [F; 25],
+        rc: u64,
+    ) -> Result<[AssignedCell<F, F>; 25], Error> {
+        let round_constant = biguint_to_f::<F>(&convert_b2_to_b13(rc));
+        self.assign(layouter, state, out_state, round_constant)
+    }
+
+    fn assign(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        out_state: [F; 25],
+        round_constant: F,
+    ) -> Result<[AssignedCell<F, F>; 25], Error> {
+        layouter.assign_region(
+            || "iota",
+            |mut region| {
+                let offset = 0;
+                self.q_enable.enable(&mut region, offset)?;
+                region.assign_fixed(
+                    || "iota round constant",
+                    self.round_constant,
+                    offset,
+                    || Value::known(round_constant),
+                )?;
+                for (lane, column) in state.iter().zip(self.state.iter()) {
+                    lane.copy_advice(|| "iota input", &mut region, *column, offset)?;
+                }
+                let out_state = out_state
+                    .iter()
+                    .zip(self.state.iter())
+                    .map(|(lane, column)| {
+                        region.assign_advice(
+                            || "iota output",
+                            *column,
+                            offset + 1,
+                            || Value::known(*lane),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(out_state.try_into().unwrap())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arith_helpers::StateBigInt;
+    use crate::common::{State, ROUND_CONSTANTS};
+    use crate::keccak_arith::KeccakFArith;
+    use crate::permutation::{load_state, state_to_f};
+    use halo2_proofs::circuit::SimpleFloorPlanner;
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use halo2_proofs::plonk::Circuit;
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    struct MyCircuit<F> {
+        in_state: [F; 25],
+        out_state: [F; 25],
+        rc: u64,
+    }
+
+    impl<F: Field> Circuit<F> for MyCircuit<F> {
+        type Config = IotaConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                in_state: [F::zero(); 25],
+                out_state: [F::zero(); 25],
+                rc: self.rc,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let state: [Column<Advice>; 25] = (0..25)
+                .map(|_| meta.advice_column())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            IotaConfig::configure(meta, state)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let in_state = load_state(&mut layouter, &config.state, &self.in_state)?;
+            config.assign_state(&mut layouter, &in_state, self.out_state, self.rc)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_iota_gate() {
+        let input: State = [
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 9223372036854775808, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0xffff_ffff_ffff_ffff, 0, 0x0123_4567_89ab_cdef],
+        ];
+        let mut in_biguint = StateBigInt::default();
+        for (x, y) in (0..5).cartesian_product(0..5) {
+            in_biguint[(x, y)] = convert_b2_to_b9(input[x][y]);
+        }
+        let rc = ROUND_CONSTANTS[3];
+        let out_biguint = KeccakFArith::iota_b9(&in_biguint, rc);
+
+        let circuit = MyCircuit::<Fr> {
+            in_state: state_to_f(&in_biguint),
+            out_state: state_to_f(&out_biguint),
+            rc,
+        };
+        let prover = MockProver::<Fr>::run(5, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // The output of iota with the round constant of another round isn't
+        // accepted.
+        let circuit = MyCircuit::<Fr> {
+            in_state: circuit.in_state,
+            out_state: circuit.out_state,
+            rc: ROUND_CONSTANTS[4],
+        };
+        let prover = MockProver::<Fr>::run(5, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
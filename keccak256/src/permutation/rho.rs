@@ -0,0 +1,297 @@
+use super::tables::FromBase13TableConfig;
+use super::{base_power, compose};
+use crate::arith_helpers::{convert_b13_coef, convert_b13_lane_to_b9, B13};
+use crate::common::ROTATION_CONSTANTS;
+use crate::gate_helpers::{biguint_to_f, f_to_biguint};
+use eth_types::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+use std::{iter::once, marker::PhantomData};
+
+/// Number of base-13 chunks of a lane out of theta, one more than the bits of
+/// a lane for the chunk carried out by the multiplication by 13.
+const N_CHUNKS: usize = 65;
+
+/// Config of the rho step, which rotates each lane of a base-13 state out of
+/// theta by its rotation offset and converts it to base 9. The lane `(x, y)` is
+/// decomposed on the row `5 * x + y` of a region into its 65 chunks, and each
+/// chunk is mapped to its parity bit by a lookup into a
+/// [`FromBase13TableConfig`]. The chunks 0 and 64 both hold a part of the
+/// lowest bit, so their sum is mapped instead.
+#[derive(Clone, Debug)]
+pub struct RhoConfig<F> {
+    q_enable: Selector,
+    q_lanes: [Selector; 25],
+    lane: Column<Advice>,
+    chunks: [Column<Advice>; N_CHUNKS],
+    // The parity bit of the chunk with the same index, where the bit 0 is
+    // the one of the sum of the chunks 0 and 64.
+    bits: [Column<Advice>; 64],
+    out: Column<Advice>,
+    table: FromBase13TableConfig<F>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> RhoConfig<F> {
+    /// Configure the rho gates and lookups, in columns of their own.
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
+        let q_enable = meta.complex_selector();
+        let q_lanes = [(); 25].map(|_| meta.selector());
+        let lane = meta.advice_column();
+        let chunks = [(); N_CHUNKS].map(|_| meta.advice_column());
+        let bits = [(); 64].map(|_| meta.advice_column());
+        let out = meta.advice_column();
+        let table = FromBase13TableConfig::configure(meta);
+        let info = table.get_base_info(false);
+        meta.enable_equality(lane);
+        meta.enable_equality(out);
+
+        meta.create_gate("rho input chunks", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let lane = meta.query_advice(lane, Rotation::cur());
+            let chunks_sum = compose(meta, &chunks, info.input_base);
+            vec![q_enable * (lane - chunks_sum)]
+        });
+
+        // The bit of the chunk `idx` ends up at the position `idx + rotation`
+        // of the lane, modulo 64.
+        for (lane_idx, q_lane) in q_lanes.iter().enumerate() {
+            let rotation = ROTATION_CONSTANTS[lane_idx / 5][lane_idx % 5] as usize;
+            meta.create_gate("rho rotated output", |meta| {
+                let q_lane = meta.query_selector(*q_lane);
+                let out = meta.query_advice(out, Rotation::cur());
+                let rotated = bits
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, bit)| {
+                        meta.query_advice(*bit, Rotation::cur())
+                            * Expression::Constant(base_power::<F>(
+                                info.output_base,
+                                (idx + rotation) % 64,
+                            ))
+                    })
+                    .reduce(|acc, bit| acc + bit)
+                    .unwrap();
+                vec![q_lane * (out - rotated)]
+            });
+        }
+
+        for (chunk, bit) in chunks[1..64].iter().zip(bits[1..].iter()) {
+            meta.lookup("rho chunk parity", |meta| {
+                let q_enable = meta.query_selector(q_enable);
+                let chunk = meta.query_advice(*chunk, Rotation::cur());
+                let bit = meta.query_advice(*bit, Rotation::cur());
+                vec![
+                    (q_enable.clone() * chunk, info.input_tc),
+                    (q_enable * bit, info.output_tc),
+                ]
+            });
+        }
+        meta.lookup("rho lowest chunks parity", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let chunks_sum = meta.query_advice(chunks[0], Rotation::cur())
+                + meta.query_advice(chunks[N_CHUNKS - 1], Rotation::cur());
+            let bit = meta.query_advice(bits[0], Rotation::cur());
+            vec![
+                (q_enable.clone() * chunks_sum, info.input_tc),
+                (q_enable * bit, info.output_tc),
+            ]
+        });
+        // The sum alone doesn't bound the chunks 0 and 64, which the
+        // decomposition of the lane needs to be unique.
+        for idx in [0, N_CHUNKS - 1] {
+            meta.lookup("rho lowest chunks range", |meta| {
+                let q_enable = meta.query_selector(q_enable);
+                let chunk = meta.query_advice(chunks[idx], Rotation::cur());
+                vec![(q_enable * chunk, info.input_tc)]
+            });
+        }
+
+        Self {
+            q_enable,
+            q_lanes,
+            lane,
+            chunks,
+            bits,
+            out,
+            table,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Load the table of the base-13 chunks and their parity bits.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.table.load(layouter)
+    }
+
+    /// Copy `state`, out of theta with the values `state_values`, into a new
+    /// region and assign its rho in base 9. Returns the cells of the rho.
+    pub fn assign_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 25],
+        state_values: [F; 25],
+    ) -> Result<[AssignedCell<F, F>; 25], Error> {
+        layouter.assign_region(
+            || "rho",
+            |mut region| {
+                let mut out_state = Vec::with_capacity(25);
+                for (offset, (lane, value)) in state.iter().zip(state_values.iter()).enumerate() {
+                    self.q_enable.enable(&mut region, offset)?;
+                    self.q_lanes[offset].enable(&mut region, offset)?;
+                    lane.copy_advice(|| "rho input", &mut region, self.lane, offset)?;
+
+                    let value = f_to_biguint(*value);
+                    let mut chunks = value.to_radix_le(B13.into());
+                    chunks.resize(N_CHUNKS, 0);
+                    for (column, chunk) in self.chunks.iter().zip(chunks.iter()) {
+                        region.assign_advice(
+                            || "rho chunk",
+                            *column,
+                            offset,
+                            || Value::known(F::from(*chunk as u64)),
+                        )?;
+                    }
+                    let bits = once(chunks[0] + chunks[N_CHUNKS - 1])
+                        .chain(chunks[1..64].iter().copied())
+                        .map(convert_b13_coef);
+                    for (column, bit) in self.bits.iter().zip(bits) {
+                        region.assign_advice(
+                            || "rho bit",
+                            *column,
+                            offset,
+                            || Value::known(F::from(bit as u64)),
+                        )?;
+                    }
+
+                    let rotation = ROTATION_CONSTANTS[offset / 5][offset % 5];
+                    let out = convert_b13_lane_to_b9(value, rotation);
+                    out_state.push(region.assign_advice(
+                        || "rho output",
+                        self.out,
+                        offset,
+                        || Value::known(biguint_to_f::<F>(&out)),
+                    )?);
+                }
+                Ok(out_state.try_into().unwrap())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arith_helpers::{convert_b2_to_b13, StateBigInt};
+    use crate::common::State;
+    use crate::keccak_arith::KeccakFArith;
+    use crate::permutation::{load_state, state_to_f};
+    use halo2_proofs::circuit::SimpleFloorPlanner;
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use halo2_proofs::plonk::Circuit;
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Clone, Debug)]
+    struct MyConfig<F> {
+        state: [Column<Advice>; 25],
+        rho: RhoConfig<F>,
+    }
+
+    struct MyCircuit<F> {
+        in_state: [F; 25],
+        out_state: [F; 25],
+    }
+
+    impl<F: Field> Circuit<F> for MyCircuit<F> {
+        type Config = MyConfig<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                in_state: [F::zero(); 25],
+                out_state: [F::zero(); 25],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let state = [(); 25].map(|_| meta.advice_column());
+            for column in state.iter() {
+                meta.enable_equality(*column);
+            }
+            MyConfig {
+                state,
+                rho: RhoConfig::configure(meta),
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            config.rho.load(&mut layouter)?;
+            let in_state = load_state(&mut layouter, &config.state, &self.in_state)?;
+            let out_state = config
+                .rho
+                .assign_state(&mut layouter, &in_state, self.in_state)?;
+            layouter.assign_region(
+                || "check state",
+                |mut region| {
+                    for ((lane, column), cell) in self
+                        .out_state
+                        .iter()
+                        .zip(config.state.iter())
+                        .zip(out_state.iter())
+                    {
+                        let expected =
+                            region.assign_advice(|| "lane", *column, 0, || Value::known(*lane))?;
+                        region.constrain_equal(expected.cell(), cell.cell())?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_rho_gate() {
+        let input: State = [
+            [1, 0, 0, 0, 0],
+            [0, 0, 0, 9223372036854775808, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0xffff_ffff_ffff_ffff, 0, 0x0123_4567_89ab_cdef],
+        ];
+        let mut in_biguint = StateBigInt::default();
+        for (x, y) in (0..5).cartesian_product(0..5) {
+            in_biguint[(x, y)] = convert_b2_to_b13(input[x][y]);
+        }
+        // Rho takes the state out of theta, with chunks up to 11.
+        let in_biguint = KeccakFArith::theta(&in_biguint);
+        let out_biguint = KeccakFArith::rho(&in_biguint);
+
+        let circuit = MyCircuit::<Fr> {
+            in_state: state_to_f(&in_biguint),
+            out_state: state_to_f(&out_biguint),
+        };
+        let prover = MockProver::<Fr>::run(9, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // The lanes converted without their rotation aren't accepted.
+        let mut out_biguint = StateBigInt::default();
+        for (x, y) in (0..5).cartesian_product(0..5) {
+            out_biguint[(x, y)] = convert_b13_lane_to_b9(in_biguint[(x, y)].clone(), 0);
+        }
+        let circuit = MyCircuit::<Fr> {
+            in_state: circuit.in_state,
+            out_state: state_to_f(&out_biguint),
+        };
+        let prover = MockProver::<Fr>::run(9, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
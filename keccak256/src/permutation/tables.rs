@@ -0,0 +1,197 @@
+use crate::arith_helpers::{convert_b13_coef, convert_b9_coef, B13, B2, B9};
+use eth_types::Field;
+use halo2_proofs::{
+    circuit::{Layouter, Value},
+    plonk::{ConstraintSystem, Error, TableColumn},
+};
+use std::marker::PhantomData;
+
+/// The bases and columns of a conversion table, as looked up by the chunks of
+/// a lane in `input_base` and the digits of the converted lane in
+/// `output_base`.
+#[derive(Clone, Copy, Debug)]
+pub struct BaseInfo {
+    /// Base of the lane the chunks are taken from
+    pub input_base: u8,
+    /// Base of the lane the outputs are recomposed into
+    pub output_base: u8,
+    /// Column of the chunks
+    pub input_tc: TableColumn,
+    /// Column of the output digit of each chunk
+    pub output_tc: TableColumn,
+}
+
+/// Table of the base-13 chunks from 0 to 12 and their parity bits. A chunk of
+/// a lane out of theta is a sum of up to 12 bits, and its parity is the bit of
+/// the lane. A single bit reads the same in base 2 and in base 9, so the table
+/// converts a base-13 chunk into either base.
+#[derive(Clone, Debug)]
+pub struct FromBase13TableConfig<F> {
+    chunk: TableColumn,
+    bit: TableColumn,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> FromBase13TableConfig<F> {
+    /// Configure the two columns of the table.
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
+        Self {
+            chunk: meta.lookup_table_column(),
+            bit: meta.lookup_table_column(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the info of the conversion from base 13 into base 2 if
+    /// `output_b2`, and into base 9 otherwise.
+    pub fn get_base_info(&self, output_b2: bool) -> BaseInfo {
+        BaseInfo {
+            input_base: B13,
+            output_base: if output_b2 { B2 } else { B9 },
+            input_tc: self.chunk,
+            output_tc: self.bit,
+        }
+    }
+
+    /// Load the base-13 chunks and their bits.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        load_chunk_table(
+            layouter,
+            "base-13 chunk parity table",
+            self.get_base_info(false),
+            from_base13_rows(),
+        )
+    }
+}
+
+/// Table of the base-9 chunks from 0 to 8 and their bits. A chunk of a lane out
+/// of chi is `2 * a + b + 3 * c + 2 * d` for the bits `a`, `b`, `c` and `d`,
+/// and its bit is `a ^ (!b & c) ^ d`. The table converts a base-9 chunk into
+/// base 2 or, as the input of the next round, into base 13.
+#[derive(Clone, Debug)]
+pub struct FromBase9TableConfig<F> {
+    chunk: TableColumn,
+    bit: TableColumn,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> FromBase9TableConfig<F> {
+    /// Configure the two columns of the table.
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Self {
+        Self {
+            chunk: meta.lookup_table_column(),
+            bit: meta.lookup_table_column(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the info of the conversion from base 9 into base 2 if
+    /// `output_b2`, and into base 13 otherwise.
+    pub fn get_base_info(&self, output_b2: bool) -> BaseInfo {
+        BaseInfo {
+            input_base: B9,
+            output_base: if output_b2 { B2 } else { B13 },
+            input_tc: self.chunk,
+            output_tc: self.bit,
+        }
+    }
+
+    /// Load the base-9 chunks and their bits.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        load_chunk_table(
+            layouter,
+            "base-9 chunk table",
+            self.get_base_info(false),
+            from_base9_rows(),
+        )
+    }
+}
+
+/// Assign the `rows` of chunks and their bits into the columns of `info`.
+fn load_chunk_table<F: Field>(
+    layouter: &mut impl Layouter<F>,
+    name: &str,
+    info: BaseInfo,
+    rows: impl Iterator<Item = (u8, u8)> + Clone,
+) -> Result<(), Error> {
+    layouter.assign_table(
+        || name,
+        |mut table| {
+            for (offset, (chunk, bit)) in rows.clone().enumerate() {
+                table.assign_cell(
+                    || "chunk",
+                    info.input_tc,
+                    offset,
+                    || Value::known(F::from(chunk as u64)),
+                )?;
+                table.assign_cell(
+                    || "bit",
+                    info.output_tc,
+                    offset,
+                    || Value::known(F::from(bit as u64)),
+                )?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// The rows of [`FromBase13TableConfig`], each base-13 chunk with its bit.
+fn from_base13_rows() -> impl Iterator<Item = (u8, u8)> + Clone {
+    (0..B13).map(|chunk| (chunk, convert_b13_coef(chunk)))
+}
+
+/// The rows of [`FromBase9TableConfig`], each base-9 chunk with its bit.
+fn from_base9_rows() -> impl Iterator<Item = (u8, u8)> + Clone {
+    (0..B9).map(|chunk| (chunk, convert_b9_coef(chunk)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_base13_rows() {
+        let rows = from_base13_rows().collect::<Vec<_>>();
+        assert_eq!(rows.len(), B13 as usize);
+        for row in [(0, 0), (1, 1), (2, 0), (7, 1), (11, 1), (12, 0)] {
+            assert!(rows.contains(&row), "{:?} not in {:?}", row, rows);
+        }
+    }
+
+    #[test]
+    fn test_from_base9_rows() {
+        let rows = from_base9_rows().collect::<Vec<_>>();
+        assert_eq!(rows.len(), B9 as usize);
+        // 2 * a + b + 3 * c + 2 * d for (a, b, c, d) being (0, 0, 0, 0),
+        // (0, 1, 0, 0), (1, 0, 0, 0), (0, 0, 1, 0), (1, 1, 1, 0) and
+        // (1, 1, 1, 1).
+        for row in [(0, 0), (1, 0), (2, 1), (3, 1), (6, 1), (8, 0)] {
+            assert!(rows.contains(&row), "{:?} not in {:?}", row, rows);
+        }
+    }
+
+    #[test]
+    fn test_get_base_info() {
+        let mut meta = ConstraintSystem::<Fr>::default();
+        let from_base13 = FromBase13TableConfig::configure(&mut meta);
+        let from_base9 = FromBase9TableConfig::configure(&mut meta);
+
+        let bases = |info: BaseInfo| (info.input_base, info.output_base);
+        assert_eq!(bases(from_base13.get_base_info(true)), (B13, B2));
+        assert_eq!(bases(from_base13.get_base_info(false)), (B13, B9));
+        assert_eq!(bases(from_base9.get_base_info(true)), (B9, B2));
+        assert_eq!(bases(from_base9.get_base_info(false)), (B9, B13));
+        // Both outputs are read from the same column of bits.
+        assert_eq!(
+            from_base13.get_base_info(true).output_tc,
+            from_base13.get_base_info(false).output_tc
+        );
+        assert_ne!(
+            from_base13.get_base_info(true).input_tc,
+            from_base9.get_base_info(true).input_tc
+        );
+    }
+}
@@ -8,19 +8,36 @@ pub struct Keccak {
 }
 
 impl Default for Keccak {
+    /// Keccak-256, as used by Ethereum.
     fn default() -> Self {
-        let security_level = (1088, 512);
+        Self::new(1088, 512, 32)
+    }
+}
+
+impl Keccak {
+    /// Returns a Keccak sponge with the given rate and capacity in bits, which
+    /// must add up to the 1600 bits of the permutation state, and producing a
+    /// digest of `output_len` bytes, which must fit in a single squeeze.
+    pub fn new(rate: usize, capacity: usize, output_len: usize) -> Self {
+        assert_eq!(
+            rate + capacity,
+            1600,
+            "rate and capacity must add up to 1600"
+        );
+        assert_eq!(rate % 64, 0, "rate must be a multiple of the lane size");
+        assert!(
+            output_len <= rate / 8,
+            "output must fit in a single squeeze"
+        );
 
         Self {
             state: [[0; 5]; 5],
-            // rate & capacity in bytes
-            sponge: Sponge::new(security_level.0 / 8, security_level.1 / 8),
+            // rate in bytes
+            sponge: Sponge::new(rate / 8, output_len),
             scratch: Vec::new(),
         }
     }
-}
 
-impl Keccak {
     pub fn update(&mut self, input: &[u8]) {
         let rate = self.sponge.rate;
         // offset for `input`
@@ -132,15 +149,17 @@ impl KeccakF {
 
 pub struct Sponge {
     rate: usize,
-    capacity: usize,
+    output_len: usize,
     keccak_f: KeccakF,
 }
 
 impl Sponge {
-    pub fn new(rate: usize, capacity: usize) -> Sponge {
+    /// Returns a sponge absorbing `rate` bytes per permutation and squeezing
+    /// `output_len` bytes.
+    pub fn new(rate: usize, output_len: usize) -> Sponge {
         Sponge {
             rate,
-            capacity,
+            output_len,
             keccak_f: KeccakF::default(),
         }
     }
@@ -176,7 +195,7 @@ impl Sponge {
     pub fn squeeze(&self, state: &mut State) -> Vec<u8> {
         let mut output: Vec<u8> = vec![];
 
-        let output_len: usize = self.capacity / 2;
+        let output_len: usize = self.output_len;
         let elems_total: usize = output_len / 8;
         let mut counter: usize = 0;
 
@@ -267,3 +286,17 @@ fn test_long_input() {
     ];
     assert_eq!(keccak256(&input), output);
 }
+
+#[test]
+fn test_keccak512_empty_input() {
+    let output = [
+        0x0e, 0xab, 0x42, 0xde, 0x4c, 0x3c, 0xeb, 0x92, 0x35, 0xfc, 0x91, 0xac, 0xff, 0xe7, 0x46,
+        0xb2, 0x9c, 0x29, 0xa8, 0xc3, 0x66, 0xb7, 0xc6, 0x0e, 0x4e, 0x67, 0xc4, 0x66, 0xf3, 0x6a,
+        0x43, 0x04, 0xc0, 0x0f, 0xa9, 0xca, 0xf9, 0xd8, 0x79, 0x76, 0xba, 0x46, 0x9b, 0xcb, 0xe0,
+        0x67, 0x13, 0xb4, 0x35, 0xf0, 0x91, 0xef, 0x27, 0x69, 0xfb, 0x16, 0x0c, 0xda, 0xb3, 0x3d,
+        0x36, 0x70, 0x68, 0x0e,
+    ];
+    let mut keccak = Keccak::new(576, 1024, 64);
+    keccak.update(&[]);
+    assert_eq!(keccak.digest(), output);
+}
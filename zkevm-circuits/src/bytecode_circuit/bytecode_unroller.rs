@@ -17,18 +17,17 @@ use halo2_proofs::{
     },
     poly::Rotation,
 };
-use keccak256::plain::Keccak;
 use std::vec;
 
 use super::param::PUSH_TABLE_WIDTH;
 /// Public data for the bytecode
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) struct BytecodeRow<F: Field> {
-    code_hash: Word,
-    tag: F,
-    index: F,
-    is_code: F,
-    value: F,
+    pub(crate) code_hash: Word,
+    pub(crate) tag: F,
+    pub(crate) index: F,
+    pub(crate) is_code: F,
+    pub(crate) value: F,
 }
 
 /// Unrolled bytecode
@@ -646,34 +645,12 @@ impl<F: Field> BytecodeCircuitConfig<F> {
 
 /// Get unrolled bytecode from raw bytes
 pub fn unroll<F: Field>(bytes: Vec<u8>) -> UnrolledBytecode<F> {
-    let code_hash = keccak(&bytes[..]);
-    let mut rows = vec![BytecodeRow::<F> {
-        code_hash,
-        tag: F::from(BytecodeFieldTag::Length as u64),
-        index: F::zero(),
-        is_code: F::zero(),
-        value: F::from(bytes.len() as u64),
-    }];
-    // Run over all the bytes
-    let mut push_rindex = 0;
-    for (index, byte) in bytes.iter().enumerate() {
-        // Track which byte is an opcode and which is push data
-        let is_code = push_rindex == 0;
-        push_rindex = if is_code {
-            get_push_size(*byte)
-        } else {
-            push_rindex - 1
-        };
-
-        rows.push(BytecodeRow::<F> {
-            code_hash,
-            tag: F::from(BytecodeFieldTag::Byte as u64),
-            index: F::from(index as u64),
-            is_code: F::from(is_code as u64),
-            value: F::from(*byte as u64),
-        });
+    let bytecode = witness::Bytecode::new(bytes);
+    let rows = bytecode.to_circuit_rows();
+    UnrolledBytecode {
+        bytes: bytecode.bytes,
+        rows,
     }
-    UnrolledBytecode { bytes, rows }
 }
 
 fn is_push(byte: u8) -> bool {
@@ -688,12 +665,6 @@ fn get_push_size(byte: u8) -> u64 {
     }
 }
 
-fn keccak(msg: &[u8]) -> Word {
-    let mut keccak = Keccak::default();
-    keccak.update(msg);
-    Word::from_big_endian(keccak.digest().as_slice())
-}
-
 fn into_words(message: &[u8]) -> Vec<u64> {
     let words_total = message.len() / 8;
     let mut words: Vec<u64> = vec![0; words_total];
@@ -727,7 +698,10 @@ impl<F: Field> BytecodeCircuit<F> {
         let bytecodes: Vec<UnrolledBytecode<F>> = block
             .bytecodes
             .iter()
-            .map(|(_, b)| unroll(b.bytes.clone()))
+            .map(|(_, b)| UnrolledBytecode {
+                bytes: b.bytes.clone(),
+                rows: b.to_circuit_rows(),
+            })
             .collect();
         Self::new(bytecodes, bytecode_size)
     }
@@ -775,6 +749,13 @@ mod tests {
     use crate::bytecode_circuit::dev::test_bytecode_circuit_unrolled;
     use eth_types::Bytecode;
     use halo2_proofs::halo2curves::bn256::Fr;
+    use keccak256::plain::Keccak;
+
+    fn keccak(msg: &[u8]) -> Word {
+        let mut keccak = Keccak::default();
+        keccak.update(msg);
+        Word::from_big_endian(keccak.digest().as_slice())
+    }
 
     fn get_randomness<F: Field>() -> F {
         F::from(123456)
@@ -852,6 +833,38 @@ mod tests {
         test_bytecode_circuit_unrolled::<Fr>(k, vec![unrolled], true);
     }
 
+    /// Verify the opcode/push data tagging of the circuit rows
+    #[test]
+    fn bytecode_to_circuit_rows() {
+        let bytecode = vec![OpcodeId::PUSH2.as_u8(), 0x12, 0x34, OpcodeId::STOP.as_u8()];
+        let rows = witness::Bytecode::new(bytecode.clone()).to_circuit_rows::<Fr>();
+        let code_hash = keccak(&bytecode);
+
+        assert_eq!(rows.len(), 1 + bytecode.len());
+        assert_eq!(
+            rows[0],
+            BytecodeRow {
+                code_hash,
+                tag: Fr::from(BytecodeFieldTag::Length as u64),
+                index: Fr::zero(),
+                is_code: Fr::zero(),
+                value: Fr::from(bytecode.len() as u64),
+            }
+        );
+        for (idx, (row, is_code)) in rows[1..].iter().zip([true, false, false, true]).enumerate() {
+            assert_eq!(
+                row,
+                &BytecodeRow {
+                    code_hash,
+                    tag: Fr::from(BytecodeFieldTag::Byte as u64),
+                    index: Fr::from(idx as u64),
+                    is_code: Fr::from(is_code as u64),
+                    value: Fr::from(bytecode[idx] as u64),
+                }
+            );
+        }
+    }
+
     /// Tests a fully empty circuit
     #[test]
     fn bytecode_empty() {
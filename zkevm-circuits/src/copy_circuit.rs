@@ -743,10 +743,17 @@ pub mod dev {
     }
 
     /// Test copy circuit with the provided block witness
+    /// On failure, the copy events of the block are logged to diff them
+    /// against the trace.
     pub fn test_copy_circuit<F: Field>(k: u32, block: Block<F>) -> Result<(), Vec<VerifyFailure>> {
+        let copy_events = block.dump_copy_events();
         let circuit = CopyCircuit::<F>::new(4, block);
         let prover = MockProver::<F>::run(k, &circuit, vec![]).unwrap();
-        prover.verify_par()
+        let result = prover.verify_par();
+        if result.is_err() {
+            log::error!("copy events:\n{}", copy_events);
+        }
+        result
     }
 }
 
@@ -755,16 +762,18 @@ mod tests {
     use super::dev::test_copy_circuit;
     use bus_mapping::evm::{gen_sha3_code, MemoryKind};
     use bus_mapping::{
-        circuit_input_builder::{CircuitInputBuilder, CircuitsParams},
+        circuit_input_builder::{CircuitInputBuilder, CircuitsParams, CopyDataType, NumberOrHash},
         mock::BlockData,
     };
     use eth_types::{bytecode, geth_types::GethData, Word};
-    use halo2_proofs::halo2curves::bn256::Fr;
+    use halo2_proofs::{circuit::Value, halo2curves::bn256::Fr};
     use mock::test_ctx::helpers::account_0_code_account_1_no_code;
     use mock::TestContext;
 
     use crate::evm_circuit::test::rand_bytes;
-    use crate::evm_circuit::witness::block_convert;
+    use crate::evm_circuit::witness::{block_convert, Rw};
+    use crate::table::{CopyCheckpoint, CopyTable, RwTableTag};
+    use crate::util::Challenges;
 
     fn gen_calldatacopy_data() -> CircuitInputBuilder {
         let length = 0x0fffusize;
@@ -872,6 +881,60 @@ mod tests {
         assert_eq!(test_copy_circuit(10, block), Ok(()));
     }
 
+    #[test]
+    fn copy_circuit_codecopy_events() {
+        let builder = gen_codecopy_data();
+        let block = block_convert::<Fr>(&builder.block, &builder.code_db).unwrap();
+
+        assert_eq!(block.copy_events.len(), 1);
+        let copy_event = &block.copy_events[0];
+        let code = match &copy_event.src_id {
+            NumberOrHash::Hash(code_hash) => &builder.code_db.0[code_hash],
+            NumberOrHash::Number(_) => panic!("bytecode source without code hash"),
+        };
+        assert_eq!(copy_event.src_type, CopyDataType::Bytecode);
+        assert_eq!(copy_event.dst_type, CopyDataType::Memory);
+        assert_eq!(copy_event.dst_id, NumberOrHash::Number(1));
+        assert_eq!((copy_event.src_addr, copy_event.dst_addr), (0, 0));
+        assert_eq!(copy_event.src_addr_end, code.len() as u64);
+        // The PUSH32 opcode, then its push data.
+        assert_eq!(
+            copy_event.bytes,
+            code[..0x20]
+                .iter()
+                .enumerate()
+                .map(|(idx, byte)| (*byte, idx == 0))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(block.dump_copy_events(), format!("{}\n", copy_event));
+        assert!(copy_event.to_string().starts_with("Bytecode(0x"));
+    }
+
+    #[test]
+    fn copy_circuit_skipped_dst_write() {
+        let builder = gen_codecopy_data();
+        let mut block = block_convert::<Fr>(&builder.block, &builder.code_db).unwrap();
+        let copy_event = &block.copy_events[0];
+        let skipped = copy_event.dst_addr + 5;
+        let past_end = copy_event.dst_addr + copy_event.length();
+
+        // Write one destination byte past the end of the copied range instead,
+        // leaving a gap in the destination addresses.
+        for rw in block.rws.0.get_mut(&RwTableTag::Memory).unwrap() {
+            if let Rw::Memory {
+                is_write: true,
+                memory_address,
+                ..
+            } = rw
+            {
+                if *memory_address == skipped {
+                    *memory_address = past_end;
+                }
+            }
+        }
+        assert!(test_copy_circuit(10, block).is_err());
+    }
+
     #[test]
     fn copy_circuit_valid_sha3() {
         let builder = gen_sha3_data();
@@ -879,6 +942,37 @@ mod tests {
         assert_eq!(test_copy_circuit(20, block), Ok(()));
     }
 
+    #[test]
+    fn copy_assignments_resumed() {
+        let challenges =
+            Challenges::mock(Value::known(Fr::from(0x100)), Value::known(Fr::from(0x200)));
+        // The SHA3 copy accumulates the RLC of the bytes across the halves.
+        for builder in [gen_codecopy_data(), gen_sha3_data()] {
+            let block = block_convert::<Fr>(&builder.block, &builder.code_db).unwrap();
+            for copy_event in block.copy_events.iter() {
+                let single_pass = CopyTable::assignments(copy_event, challenges);
+
+                let half = copy_event.bytes.len() / 2;
+                let (mut resumed, checkpoint) = CopyTable::resume_assignments(
+                    copy_event,
+                    challenges,
+                    CopyCheckpoint::start(copy_event),
+                    half,
+                );
+                assert_eq!(checkpoint.src_addr, copy_event.src_addr + half as u64);
+                assert_eq!(checkpoint.dst_addr, copy_event.dst_addr + half as u64);
+                assert_eq!(checkpoint.bytes_left, copy_event.length() - half as u64);
+                let (rest, checkpoint) =
+                    CopyTable::resume_assignments(copy_event, challenges, checkpoint, usize::MAX);
+                assert!(checkpoint.is_done());
+                resumed.extend(rest);
+
+                // Values aren't comparable, their debug output is.
+                assert_eq!(format!("{:?}", resumed), format!("{:?}", single_pass));
+            }
+        }
+    }
+
     #[test]
     fn copy_circuit_tx_log() {
         let builder = gen_tx_log_data();
@@ -21,14 +21,13 @@ use eth_types::Field;
 use execution::ExecutionConfig;
 use itertools::Itertools;
 use strum::IntoEnumIterator;
-use table::FixedTableTag;
+use table::{FixedTableRegistry, FixedTableTag};
 use witness::Block;
 
 /// EvmCircuitConfig implements verification of execution trace of a block.
 #[derive(Clone, Debug)]
 pub struct EvmCircuitConfig<F> {
-    fixed_table: [Column<Fixed>; 4],
-    byte_table: [Column<Fixed>; 1],
+    fixed_tables: FixedTableRegistry,
     pub(crate) execution: Box<ExecutionConfig<F>>,
     // External tables
     tx_table: TxTable,
@@ -78,13 +77,11 @@ impl<F: Field> SubCircuitConfig<F> for EvmCircuitConfig<F> {
             exp_table,
         }: Self::ConfigArgs,
     ) -> Self {
-        let fixed_table = [(); 4].map(|_| meta.fixed_column());
-        let byte_table = [(); 1].map(|_| meta.fixed_column());
+        let fixed_tables = FixedTableRegistry::configure(meta);
         let execution = Box::new(ExecutionConfig::configure(
             meta,
             power_of_randomness,
-            &fixed_table,
-            &byte_table,
+            &fixed_tables,
             &tx_table,
             &rw_table,
             &bytecode_table,
@@ -95,8 +92,7 @@ impl<F: Field> SubCircuitConfig<F> for EvmCircuitConfig<F> {
         ));
 
         Self {
-            fixed_table,
-            byte_table,
+            fixed_tables,
             execution,
             tx_table,
             rw_table,
@@ -123,7 +119,7 @@ impl<F: Field> EvmCircuitConfig<F> {
                     .chain(fixed_table_tags.iter().flat_map(|tag| tag.build()))
                     .enumerate()
                 {
-                    for (column, value) in self.fixed_table.iter().zip_eq(row) {
+                    for (column, value) in self.fixed_tables.fixed_table.iter().zip_eq(row) {
                         region.assign_fixed(|| "", *column, offset, || Value::known(value))?;
                     }
                 }
@@ -141,7 +137,7 @@ impl<F: Field> EvmCircuitConfig<F> {
                 for offset in 0..256 {
                     region.assign_fixed(
                         || "",
-                        self.byte_table[0],
+                        self.fixed_tables.byte_table[0],
                         offset,
                         || Value::known(F::from(offset as u64)),
                     )?;
@@ -268,20 +264,24 @@ pub(crate) fn detect_fixed_table_tags<F: Field>(block: &Block<F>) -> Vec<FixedTa
 pub mod test {
     use super::*;
     use crate::{
-        evm_circuit::{witness::Block, EvmCircuitConfig},
+        evm_circuit::{
+            witness::{Block, ExecStep, Rw},
+            EvmCircuitConfig,
+        },
         exp_circuit::OFFSET_INCREMENT,
         table::{BlockTable, BytecodeTable, CopyTable, ExpTable, KeccakTable, RwTable, TxTable},
         util::{power_of_randomness_from_instance, Challenges},
         witness::block_convert,
     };
     use bus_mapping::{circuit_input_builder::CircuitsParams, mock::BlockData};
-    use eth_types::{geth_types::GethData, Field, Word};
+    use eth_types::{geth_types::GethData, Bytecode, Field, Word};
     use halo2_proofs::halo2curves::bn256::Fr;
     use halo2_proofs::{
         circuit::{Layouter, SimpleFloorPlanner, Value},
         dev::{MockProver, VerifyFailure},
         plonk::{Circuit, ConstraintSystem, Error},
     };
+    use mock::TestContext;
     use rand::{
         distributions::uniform::{SampleRange, SampleUniform},
         random, thread_rng, Rng,
@@ -395,17 +395,55 @@ pub mod test {
         }
     }
 
-    pub fn run_test_circuit_geth_data_default<F: Field>(
-        block: GethData,
-    ) -> Result<(), Vec<VerifyFailure>> {
+    /// Build the witness block of `block` with the default circuits
+    /// parameters.
+    pub fn witness_block_geth_data_default(block: GethData) -> Block<Fr> {
         let mut builder =
             BlockData::new_from_geth_data_with_params(block.clone(), CircuitsParams::default())
                 .new_circuit_input_builder();
         builder
             .handle_block(&block.eth_block, &block.geth_traces)
             .unwrap();
-        let block = block_convert::<Fr>(&builder.block, &builder.code_db).unwrap();
-        run_test_circuit(block)
+        block_convert::<Fr>(&builder.block, &builder.code_db).unwrap()
+    }
+
+    /// Build the witness block of a tx calling `bytecode`, as set up by
+    /// [`TestContext::simple_ctx_with_bytecode`].
+    pub fn witness_block_with_bytecode(bytecode: Bytecode) -> Block<Fr> {
+        witness_block_geth_data_default(
+            TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode)
+                .unwrap()
+                .into(),
+        )
+    }
+
+    /// Returns the steps of the first tx of `block` selected by `filter`, each
+    /// with the value it pushes, i.e. its first stack write.
+    pub fn pushed_values<'a>(
+        block: &'a Block<Fr>,
+        filter: impl Fn(&ExecStep) -> bool,
+    ) -> Vec<(&'a ExecStep, Word)> {
+        block.txs[0]
+            .steps
+            .iter()
+            .filter(|step| filter(step))
+            .map(|step| {
+                let pushed = step
+                    .rw_indices
+                    .iter()
+                    .map(|idx| &block.rws[*idx])
+                    .find(|rw| matches!(rw, Rw::Stack { is_write: true, .. }))
+                    .unwrap_or_else(|| panic!("{:?} pushes nothing", step.execution_state))
+                    .stack_value();
+                (step, pushed)
+            })
+            .collect()
+    }
+
+    pub fn run_test_circuit_geth_data_default<F: Field>(
+        block: GethData,
+    ) -> Result<(), Vec<VerifyFailure>> {
+        run_test_circuit(witness_block_geth_data_default(block))
     }
 
     pub fn run_test_circuit_geth_data<F: Field>(
@@ -504,7 +542,7 @@ pub mod test {
 mod evm_circuit_stats {
     use super::test::*;
     use super::*;
-    use crate::evm_circuit::step::ExecutionState;
+    use crate::evm_circuit::{param::LOOKUP_CONFIG, step::ExecutionState, table::Table};
     use eth_types::{bytecode, evm_types::OpcodeId, geth_types::GethData};
     use halo2_proofs::halo2curves::bn256::Fr;
     use halo2_proofs::plonk::ConstraintSystem;
@@ -519,6 +557,40 @@ mod evm_circuit_stats {
         run_test_circuit_geth_data_default::<Fr>(block).unwrap();
     }
 
+    #[test]
+    fn byte_table_allocated_once() {
+        let mut meta = ConstraintSystem::<Fr>::default();
+        let config = EvmCircuit::configure(&mut meta);
+
+        // The byte cells of all the gadgets are allocated by the cell manager
+        // of the step in the byte lookup columns of LOOKUP_CONFIG, which are
+        // all looked up into the one byte table column of the registry. The
+        // byte table is the only table made of a single fixed column, so the
+        // columns of those lookups are the byte tables actually configured.
+        let num_byte_lookups = LOOKUP_CONFIG
+            .iter()
+            .find(|(table, _)| *table == Table::Byte)
+            .unwrap()
+            .1;
+        assert_eq!(
+            config.execution.num_lookup_columns(Table::Byte),
+            num_byte_lookups
+        );
+        let byte_table_columns = meta
+            .lookups()
+            .iter()
+            .filter_map(|lookup| match lookup.table_expressions().as_slice() {
+                [Expression::Fixed(query)] => Some(query.column_index()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(byte_table_columns.len(), num_byte_lookups);
+        assert_eq!(
+            byte_table_columns.into_iter().unique().collect::<Vec<_>>(),
+            vec![config.fixed_tables.byte_table[0].index()]
+        );
+    }
+
     /// This function prints to stdout a table with all the implemented states
     /// and their responsible opcodes with the following stats:
     /// - height: number of rows in the EVM circuit used by the execution state
@@ -3,7 +3,7 @@ use crate::{
     evm_circuit::{
         param::{MAX_STEP_HEIGHT, STEP_WIDTH},
         step::{ExecutionState, Step},
-        table::Table,
+        table::{FixedTableRegistry, Table},
         util::{
             constraint_builder::{BaseConstraintBuilder, ConstraintBuilder},
             rlc, CellType,
@@ -54,8 +54,10 @@ mod error_invalid_jump;
 mod error_oog_call;
 mod error_oog_constant;
 mod error_oog_static_memory;
+mod error_return_data_oob;
 mod error_stack;
 mod exp;
+mod extcodecopy;
 mod extcodehash;
 mod extcodesize;
 mod gas;
@@ -78,6 +80,7 @@ mod push;
 mod return_revert;
 mod returndatacopy;
 mod returndatasize;
+mod sar;
 mod sdiv_smod;
 mod selfbalance;
 mod sha3;
@@ -116,8 +119,10 @@ use end_tx::EndTxGadget;
 use error_invalid_jump::ErrorInvalidJumpGadget;
 use error_oog_call::ErrorOOGCallGadget;
 use error_oog_constant::ErrorOOGConstantGadget;
+use error_return_data_oob::ErrorReturnDataOutOfBoundGadget;
 use error_stack::ErrorStackGadget;
 use exp::ExponentiationGadget;
+use extcodecopy::ExtcodecopyGadget;
 use extcodehash::ExtcodehashGadget;
 use extcodesize::ExtcodesizeGadget;
 use gas::GasGadget;
@@ -139,6 +144,7 @@ use push::PushGadget;
 use return_revert::ReturnRevertGadget;
 use returndatacopy::ReturnDataCopyGadget;
 use returndatasize::ReturnDataSizeGadget;
+use sar::SarGadget;
 use sdiv_smod::SignedDivModGadget;
 use selfbalance::SelfbalanceGadget;
 use shl_shr::ShlShrGadget;
@@ -211,6 +217,7 @@ pub(crate) struct ExecutionConfig<F> {
     comparator_gadget: ComparatorGadget<F>,
     dup_gadget: DupGadget<F>,
     exp_gadget: ExponentiationGadget<F>,
+    extcodecopy_gadget: ExtcodecopyGadget<F>,
     extcodehash_gadget: ExtcodehashGadget<F>,
     extcodesize_gadget: ExtcodesizeGadget<F>,
     gas_gadget: GasGadget<F>,
@@ -230,12 +237,11 @@ pub(crate) struct ExecutionConfig<F> {
     pop_gadget: PopGadget<F>,
     push_gadget: PushGadget<F>,
     return_revert_gadget: ReturnRevertGadget<F>,
+    sar_gadget: SarGadget<F>,
     sdiv_smod_gadget: SignedDivModGadget<F>,
     selfbalance_gadget: SelfbalanceGadget<F>,
     sha3_gadget: Sha3Gadget<F>,
     shl_shr_gadget: ShlShrGadget<F>,
-    sar_gadget: DummyGadget<F, 2, 1, { ExecutionState::SAR }>,
-    extcodecopy_gadget: DummyGadget<F, 4, 0, { ExecutionState::EXTCODECOPY }>,
     returndatasize_gadget: ReturnDataSizeGadget<F>,
     returndatacopy_gadget: ReturnDataCopyGadget<F>,
     create_gadget: DummyGadget<F, 3, 1, { ExecutionState::CREATE }>,
@@ -280,8 +286,7 @@ pub(crate) struct ExecutionConfig<F> {
     error_contract_address_collision:
         DummyGadget<F, 0, 0, { ExecutionState::ErrorContractAddressCollision }>,
     error_invalid_creation_code: DummyGadget<F, 0, 0, { ExecutionState::ErrorInvalidCreationCode }>,
-    error_return_data_out_of_bound:
-        DummyGadget<F, 0, 0, { ExecutionState::ErrorReturnDataOutOfBound }>,
+    error_return_data_out_of_bound: ErrorReturnDataOutOfBoundGadget<F>,
     invalid_opcode_gadget: DummyGadget<F, 0, 0, { ExecutionState::ErrorInvalidOpcode }>,
 }
 
@@ -290,8 +295,7 @@ impl<F: Field> ExecutionConfig<F> {
     pub(crate) fn configure(
         meta: &mut ConstraintSystem<F>,
         power_of_randomness: [Expression<F>; 31],
-        fixed_table: &dyn LookupTable<F>,
-        byte_table: &dyn LookupTable<F>,
+        fixed_tables: &FixedTableRegistry,
         tx_table: &dyn LookupTable<F>,
         rw_table: &dyn LookupTable<F>,
         bytecode_table: &dyn LookupTable<F>,
@@ -524,8 +528,7 @@ impl<F: Field> ExecutionConfig<F> {
 
         Self::configure_lookup(
             meta,
-            fixed_table,
-            byte_table,
+            fixed_tables,
             tx_table,
             rw_table,
             bytecode_table,
@@ -546,7 +549,18 @@ impl<F: Field> ExecutionConfig<F> {
 
     pub fn get_step_height(&self, execution_state: ExecutionState) -> usize {
         self.get_step_height_option(execution_state)
-            .unwrap_or_else(|| panic!("Execution state unknown: {:?}", execution_state))
+            .unwrap_or_else(|| panic!("Execution state unknown: {}", execution_state))
+    }
+
+    /// Returns the number of cell columns of the step looked up into `table`.
+    #[cfg(test)]
+    pub(crate) fn num_lookup_columns(&self, table: Table) -> usize {
+        self.step
+            .cell_manager
+            .columns()
+            .iter()
+            .filter(|column| column.cell_type == CellType::Lookup(table))
+            .count()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -704,8 +718,7 @@ impl<F: Field> ExecutionConfig<F> {
     #[allow(clippy::too_many_arguments)]
     fn configure_lookup(
         meta: &mut ConstraintSystem<F>,
-        fixed_table: &dyn LookupTable<F>,
-        byte_table: &dyn LookupTable<F>,
+        fixed_tables: &FixedTableRegistry,
         tx_table: &dyn LookupTable<F>,
         rw_table: &dyn LookupTable<F>,
         bytecode_table: &dyn LookupTable<F>,
@@ -716,6 +729,8 @@ impl<F: Field> ExecutionConfig<F> {
         power_of_randomness: &[Expression<F>; 31],
         cell_manager: &CellManager<F>,
     ) {
+        let fixed_table: &dyn LookupTable<F> = &fixed_tables.fixed_table;
+        let byte_table: &dyn LookupTable<F> = &fixed_tables.byte_table;
         for column in cell_manager.columns().iter() {
             if let CellType::Lookup(table) = column.cell_type {
                 let name = format!("{:?}", table);
@@ -1119,7 +1134,7 @@ impl<F: Field> ExecutionConfig<F> {
                 assign_exec_step!(self.invalid_opcode_gadget)
             }
 
-            _ => unimplemented!("unimplemented ExecutionState: {:?}", step.execution_state),
+            _ => unimplemented!("unimplemented ExecutionState: {}", step.execution_state),
         }
 
         // Fill in the witness values for stored expressions
@@ -1143,7 +1158,7 @@ impl<F: Field> ExecutionConfig<F> {
         for stored_expression in self
             .stored_expressions_map
             .get(&step.execution_state)
-            .unwrap_or_else(|| panic!("Execution state unknown: {:?}", step.execution_state))
+            .unwrap_or_else(|| panic!("Execution state unknown: {}", step.execution_state))
         {
             let assigned = stored_expression.assign(region, offset)?;
             assigned.value().map(|v| {
@@ -55,11 +55,11 @@ impl<F: Field> ExecutionGadget<F> for AddSubGadget<F> {
         cb.stack_push(select::expr(is_sub.expr().0, a.expr(), c.expr()));
 
         // State transition
+        cb.charge_gas(OpcodeId::ADD.constant_gas_cost().expr());
         let step_state_transition = StepStateTransition {
             rw_counter: Delta(3.expr()),
             program_counter: Delta(1.expr()),
             stack_pointer: Delta(1.expr()),
-            gas_left: Delta(-OpcodeId::ADD.constant_gas_cost().expr()),
             ..StepStateTransition::default()
         };
         let same_context = SameContextGadget::construct(cb, opcode, step_state_transition);
@@ -133,6 +133,16 @@ mod test {
         test_ok(OpcodeId::SUB, 0x090705.into(), 0x060504.into());
     }
 
+    #[test]
+    fn add_gadget_overflow() {
+        // Results wrap around modulo 2^256.
+        test_ok(OpcodeId::ADD, Word::MAX, 0x01.into());
+        test_ok(OpcodeId::ADD, Word::MAX, Word::MAX);
+        test_ok(OpcodeId::SUB, Word::MAX, 0x01.into());
+        test_ok(OpcodeId::SUB, 0x01.into(), Word::MAX);
+        test_ok(OpcodeId::SUB, Word::zero(), 0x01.into());
+    }
+
     #[test]
     fn add_gadget_rand() {
         let a = rand_word();
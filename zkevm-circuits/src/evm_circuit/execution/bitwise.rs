@@ -135,6 +135,14 @@ mod test {
         test_ok(0x12_34_56.into(), 0x78_9A_BC.into());
     }
 
+    #[test]
+    fn bitwise_gadget_same_operand() {
+        // a AND a == a, a OR a == a, a XOR a == 0
+        let a = rand_word();
+        test_ok(a, a);
+        test_ok(Word::MAX, Word::MAX);
+    }
+
     #[test]
     fn bitwise_gadget_rand() {
         let a = rand_word();
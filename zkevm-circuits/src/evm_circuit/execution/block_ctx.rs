@@ -189,9 +189,16 @@ impl<F: Field> ExecutionGadget<F> for BlockCtxU256Gadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
-    use eth_types::bytecode;
-    use mock::TestContext;
+    use crate::{
+        evm_circuit::test::{pushed_values, run_test_circuit, witness_block_geth_data_default},
+        test_util::run_test_circuits,
+    };
+    use bus_mapping::evm::OpcodeId;
+    use eth_types::{address, bytecode, ToWord, Word};
+    use mock::{
+        test_ctx::helpers::{account_0_code_account_1_no_code, tx_from_1_to_0},
+        TestContext, MOCK_CHAIN_ID,
+    };
 
     fn test_ok(bytecode: bytecode::Bytecode) {
         assert_eq!(
@@ -234,4 +241,57 @@ mod test {
         };
         test_ok(bytecode);
     }
+
+    #[test]
+    fn blockcxt_gadget_pushes_block_fields() {
+        let coinbase = address!("0x00000000000000000000000000000000c014ba5e");
+        let (timestamp, number, difficulty) = (0x6325_4100u64, 0xcafe_u64, 0x2_0000_0001u64);
+        let (gas_limit, base_fee) = (0x1_0000_0000u64, 1u64);
+        let bytecode = bytecode! {
+            COINBASE
+            TIMESTAMP
+            NUMBER
+            DIFFICULTY
+            GASLIMIT
+            CHAINID
+            BASEFEE
+            STOP
+        };
+        let ctx = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(bytecode),
+            tx_from_1_to_0,
+            |block, _txs| {
+                block
+                    .author(coinbase)
+                    .timestamp(timestamp.into())
+                    .number(number)
+                    .difficulty(difficulty.into())
+                    .gas_limit(gas_limit.into())
+                    .base_fee_per_gas(base_fee.into())
+            },
+        )
+        .unwrap();
+        let block = witness_block_geth_data_default(ctx.into());
+
+        let pushed = pushed_values(&block, |step| {
+            !matches!(step.opcode, None | Some(OpcodeId::STOP))
+        })
+        .into_iter()
+        .map(|(step, value)| (step.opcode.unwrap(), value))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            pushed,
+            vec![
+                (OpcodeId::COINBASE, coinbase.to_word()),
+                (OpcodeId::TIMESTAMP, Word::from(timestamp)),
+                (OpcodeId::NUMBER, Word::from(number)),
+                (OpcodeId::DIFFICULTY, Word::from(difficulty)),
+                (OpcodeId::GASLIMIT, Word::from(gas_limit)),
+                (OpcodeId::CHAINID, *MOCK_CHAIN_ID),
+                (OpcodeId::BASEFEE, Word::from(base_fee)),
+            ]
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -148,11 +148,14 @@ impl<F: Field> ExecutionGadget<F> for BlockHashGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::test_circuits_block_geth_data_default;
-    use eth_types::{bytecode, geth_types::GethData, U256};
+    use crate::{
+        evm_circuit::test::{pushed_values, run_test_circuit, witness_block_geth_data_default},
+        test_util::test_circuits_block_geth_data_default,
+    };
+    use eth_types::{bytecode, evm_types::OpcodeId, geth_types::GethData, Word, U256};
     use mock::test_ctx::{helpers::*, TestContext};
 
-    fn test_ok(block_number: usize, current_block_number: u64) {
+    fn block_with_history(block_number: usize, current_block_number: u64) -> GethData {
         let code = bytecode! {
             PUSH32(block_number)
             BLOCKHASH
@@ -169,18 +172,41 @@ mod test {
         for i in range {
             history_hashes.push(U256::from(0xbeefcafeu64 + i));
         }
-        let block: GethData = TestContext::<2, 1>::new(
+        TestContext::<2, 1>::new(
             Some(history_hashes),
             account_0_code_account_1_no_code(code),
             tx_from_1_to_0,
             |block, _tx| block.number(current_block_number),
         )
         .unwrap()
-        .into();
+        .into()
+    }
 
+    fn test_ok(block_number: usize, current_block_number: u64) {
+        let block = block_with_history(block_number, current_block_number);
         test_circuits_block_geth_data_default(block).unwrap();
     }
 
+    fn test_pushed(block_number: usize, current_block_number: u64, expected: Word) {
+        let block =
+            witness_block_geth_data_default(block_with_history(block_number, current_block_number));
+        let (_, pushed) = pushed_values(&block, |step| step.opcode == Some(OpcodeId::BLOCKHASH))[0];
+        assert_eq!(pushed, expected);
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
+
+    #[test]
+    fn blockhash_gadget_pushes_history_hash() {
+        let hash = |number: u64| Word::from(0xbeefcafeu64 + number);
+        // One of the 256 most recent blocks.
+        test_pushed(0xcafe - 256, 0xcafe, hash(0xcafe - 256));
+        test_pushed(0xcafe - 1, 0xcafe, hash(0xcafe - 1));
+        // The current block, a future one and one more than 256 blocks back.
+        test_pushed(0xcafe, 0xcafe, Word::zero());
+        test_pushed(0xcafe + 1, 0xcafe, Word::zero());
+        test_pushed(0xcafe - 257, 0xcafe, Word::zero());
+    }
+
     #[test]
     fn blockhash_gadget_simple() {
         test_ok(0, 5);
@@ -156,6 +156,17 @@ mod test {
         test_ok(256.into(), 0x030201.into());
     }
 
+    #[test]
+    fn byte_gadget_bounds() {
+        let value = Word::from_big_endian(&(1..33).collect::<Vec<_>>()[..]);
+        // Most significant byte
+        test_ok(0.into(), value);
+        // Least significant byte
+        test_ok(31.into(), value);
+        // Out of range index returns 0
+        test_ok(32.into(), value);
+    }
+
     #[test]
     fn byte_gadget_rand() {
         let index = rand_word();
@@ -105,6 +105,9 @@ impl<F: Field> ExecutionGadget<F> for CallDataCopyGadget<F> {
         );
 
         let copy_rwc_inc = cb.query_cell();
+        // The call data is copied from the tx only in the root call, where
+        // `src_id` is the tx id, and from the caller's memory otherwise, so
+        // the source of the copy event can't be mislabeled.
         let src_tag = select::expr(
             cb.curr.state.is_root.expr(),
             CopyDataType::TxCalldata.expr(),
@@ -249,7 +252,11 @@ impl<F: Field> ExecutionGadget<F> for CallDataCopyGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::{evm_circuit::test::rand_bytes, test_util::run_test_circuits};
+    use crate::{
+        evm_circuit::test::{rand_bytes, run_test_circuit, witness_block_geth_data_default},
+        test_util::run_test_circuits,
+    };
+    use bus_mapping::circuit_input_builder::{CopyDataType, NumberOrHash};
     use eth_types::{bytecode, ToWord, Word};
     use mock::test_ctx::{helpers::*, TestContext};
 
@@ -286,13 +293,16 @@ mod test {
         assert_eq!(run_test_circuits(ctx, None,), Ok(()));
     }
 
-    fn test_ok_internal(
+    /// Context of a tx whose root call calls code B with the call data at
+    /// `call_data_offset..call_data_offset + call_data_length` of its memory,
+    /// and code B copies `length` bytes of it from `offset` to `dst_offset`.
+    fn test_ctx_internal(
         call_data_offset: usize,
         call_data_length: usize,
         dst_offset: usize,
         offset: usize,
         length: usize,
-    ) {
+    ) -> TestContext<3, 1> {
         let (addr_a, addr_b) = (mock::MOCK_ACCOUNTS[0], mock::MOCK_ACCOUNTS[1]);
 
         // code B gets called by code A, so the call is an internal call.
@@ -323,7 +333,7 @@ mod test {
             STOP
         };
 
-        let ctx = TestContext::<3, 1>::new(
+        TestContext::<3, 1>::new(
             None,
             |accs| {
                 accs[0].address(addr_b).code(code_b);
@@ -337,8 +347,23 @@ mod test {
             },
             |block, _tx| block,
         )
-        .unwrap();
+        .unwrap()
+    }
 
+    fn test_ok_internal(
+        call_data_offset: usize,
+        call_data_length: usize,
+        dst_offset: usize,
+        offset: usize,
+        length: usize,
+    ) {
+        let ctx = test_ctx_internal(
+            call_data_offset,
+            call_data_length,
+            dst_offset,
+            offset,
+            length,
+        );
         assert_eq!(run_test_circuits(ctx, None,), Ok(()));
     }
 
@@ -360,6 +385,35 @@ mod test {
         test_ok_internal(0x40, 0x20, 0xA0, 0x28, 10);
     }
 
+    #[test]
+    fn calldatacopy_gadget_offset_past_end() {
+        // All the copied bytes are zero padding.
+        test_ok_root(0x20, 0x00, 0x40, 0x20);
+        test_ok_internal(0x00, 0x20, 0x40, 0x30, 0x20);
+    }
+
+    #[test]
+    fn calldatacopy_gadget_large_out_of_bound() {
+        test_ok_root(0x40, 0x00, 0x20, 0x200);
+        test_ok_internal(0x10, 0x40, 0x00, 0x20, 0x200);
+    }
+
+    #[test]
+    fn calldatacopy_gadget_internal_not_from_tx() {
+        let ctx = test_ctx_internal(0x40, 0x40, 0xA0, 0x10, 10);
+        let mut block = witness_block_geth_data_default(ctx.into());
+        assert_eq!(run_test_circuit(block.clone()), Ok(()));
+
+        // Labeling the copy in the internal call as a copy from the tx call
+        // data is rejected by the copy table lookup.
+        let tx_id = block.txs[0].id;
+        let copy_event = &mut block.copy_events[0];
+        assert_eq!(copy_event.src_type, CopyDataType::Memory);
+        copy_event.src_type = CopyDataType::TxCalldata;
+        copy_event.src_id = NumberOrHash::Number(tx_id);
+        assert!(run_test_circuit(block).is_err());
+    }
+
     #[test]
     fn calldatacopy_gadget_zero_length() {
         test_ok_root(0x40, 0x40, 0x00, 0);
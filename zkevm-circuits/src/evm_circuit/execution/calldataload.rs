@@ -266,6 +266,34 @@ mod test {
         );
     }
 
+    fn test_root_call_data_ok(call_data_length: usize, offset: usize) {
+        let bytecode = bytecode! {
+            PUSH32(Word::from(offset))
+            CALLDATALOAD
+            STOP
+        };
+
+        let ctx = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(mock::MOCK_ACCOUNTS[0])
+                    .balance(Word::from(1u64 << 30));
+                accs[1].address(mock::MOCK_ACCOUNTS[1]).code(bytecode);
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .input(rand_bytes(call_data_length).into());
+            },
+            |block, _tx| block,
+        )
+        .unwrap();
+
+        assert_eq!(run_test_circuits(ctx, None), Ok(()));
+    }
+
     fn test_internal_ok(call_data_length: usize, call_data_offset: usize, offset: usize) {
         let (addr_a, addr_b) = (mock::MOCK_ACCOUNTS[0], mock::MOCK_ACCOUNTS[1]);
 
@@ -321,6 +349,23 @@ mod test {
         test_root_ok(0x2010);
     }
 
+    #[test]
+    fn calldataload_gadget_root_straddling_end() {
+        // The loaded word is zero-padded past the end of the call data.
+        test_root_call_data_ok(0x20, 0x00);
+        test_root_call_data_ok(0x20, 0x01);
+        test_root_call_data_ok(0x24, 0x10);
+        test_root_call_data_ok(0x05, 0x04);
+        test_root_call_data_ok(0x05, 0x05);
+    }
+
+    #[test]
+    fn calldataload_gadget_empty_call_data() {
+        test_root_call_data_ok(0x00, 0x00);
+        test_internal_ok(0x00, 0x00, 0x00);
+        test_internal_ok(0x00, 0x10, 0x08);
+    }
+
     #[test]
     fn calldataload_gadget_internal() {
         test_internal_ok(0x20, 0x00, 0x00);
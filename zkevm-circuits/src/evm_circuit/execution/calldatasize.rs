@@ -172,4 +172,10 @@ mod test {
             test_ok(call_data_size, is_root);
         }
     }
+
+    #[test]
+    fn calldatasize_gadget_empty_call_data() {
+        test_ok(0, true);
+        test_ok(0, false);
+    }
 }
@@ -89,9 +89,13 @@ impl<F: Field> ExecutionGadget<F> for CallerGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
-    use eth_types::bytecode;
-    use mock::TestContext;
+    use crate::{
+        evm_circuit::test::{pushed_values, run_test_circuit, witness_block_geth_data_default},
+        test_util::run_test_circuits,
+    };
+    use bus_mapping::evm::OpcodeId;
+    use eth_types::{bytecode, ToWord, Word};
+    use mock::{eth, TestContext, MOCK_ACCOUNTS};
 
     #[test]
     fn caller_gadget_test() {
@@ -108,4 +112,65 @@ mod test {
             Ok(())
         );
     }
+
+    #[test]
+    fn caller_gadget_internal_call() {
+        let (addr_a, addr_b, sender) = (MOCK_ACCOUNTS[0], MOCK_ACCOUNTS[1], MOCK_ACCOUNTS[2]);
+        let value = Word::from(0x10);
+
+        let code_b = bytecode! {
+            ORIGIN
+            CALLER
+            CALLVALUE
+            ADDRESS
+            STOP
+        };
+        // code A calls code B with some value, so in code B the caller is A
+        // while the origin is still the sender of the tx.
+        let code_a = bytecode! {
+            PUSH1(0x00) // retLength
+            PUSH1(0x00) // retOffset
+            PUSH1(0x00) // argsLength
+            PUSH1(0x00) // argsOffset
+            PUSH32(value) // value
+            PUSH32(addr_b.to_word()) // addr
+            PUSH32(0x1_0000) // gas
+            CALL
+            STOP
+        };
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(addr_a).code(code_a).balance(eth(1));
+                accs[1].address(addr_b).code(code_b);
+                accs[2].address(sender).balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[2].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap();
+        let block = witness_block_geth_data_default(ctx.into());
+
+        let tx = &block.txs[0];
+        let pushed = pushed_values(&block, |step| {
+            matches!(
+                step.opcode,
+                Some(OpcodeId::ORIGIN | OpcodeId::CALLER | OpcodeId::CALLVALUE | OpcodeId::ADDRESS)
+            )
+        })
+        .into_iter()
+        .map(|(step, value)| {
+            assert_eq!(tx.calls[step.call_index].depth, 2);
+            value
+        })
+        .collect::<Vec<_>>();
+        assert_eq!(
+            pushed,
+            vec![sender.to_word(), addr_a.to_word(), value, addr_b.to_word()]
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -236,4 +236,19 @@ mod tests {
     fn codecopy_gadget_large() {
         test_ok(0x103, 0x102, 0x101, true);
     }
+
+    #[test]
+    fn codecopy_gadget_out_of_bound() {
+        // The code is 0x65 bytes long without the large prefix and 0x267 bytes
+        // long with it, the bytes past its end are copied as zeros.
+        test_ok(0x00, 0x50, 0x40, false);
+        test_ok(0x40, 0x100, 0x20, false);
+        test_ok(0x00, 0x250, 0x40, true);
+    }
+
+    #[test]
+    fn codecopy_gadget_zero_length() {
+        test_ok(0x00, 0x00, 0x00, false);
+        test_ok(0x40, 0x100, 0x00, false);
+    }
 }
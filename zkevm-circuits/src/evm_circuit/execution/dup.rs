@@ -113,10 +113,41 @@ mod test {
     fn dup_gadget_simple() {
         test_ok(OpcodeId::DUP1, Word::max_value());
         test_ok(OpcodeId::DUP2, Word::max_value());
+        test_ok(OpcodeId::DUP8, Word::max_value());
         test_ok(OpcodeId::DUP15, Word::max_value());
         test_ok(OpcodeId::DUP16, Word::max_value());
     }
 
+    fn test_stack_underflow(opcode: OpcodeId, value: Word) {
+        // Only n - 1 values are on the stack when DUPn reads at depth n.
+        let n = opcode.postfix().expect("opcode with postfix");
+        let mut bytecode = bytecode! {
+            PUSH32(value)
+        };
+        for _ in 0..n - 2 {
+            bytecode.write_op(OpcodeId::DUP1);
+        }
+        bytecode.append(&bytecode! {
+            .write_op(opcode)
+            STOP
+        });
+
+        assert_eq!(
+            run_test_circuits(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                None
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn dup_gadget_underflow() {
+        test_stack_underflow(OpcodeId::DUP2, Word::max_value());
+        test_stack_underflow(OpcodeId::DUP8, Word::max_value());
+        test_stack_underflow(OpcodeId::DUP16, Word::max_value());
+    }
+
     #[test]
     #[ignore]
     fn dup_gadget_rand() {
@@ -0,0 +1,293 @@
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        step::ExecutionState,
+        util::{
+            common_gadget::RestoreContextGadget,
+            constraint_builder::{
+                ConstraintBuilder, StepStateTransition,
+                Transition::{Delta, Same},
+            },
+            from_bytes,
+            math_gadget::{IsZeroGadget, LtGadget},
+            sum, CachedRegion, Cell, Word,
+        },
+        witness::{Block, Call, ExecStep, Transaction},
+    },
+    table::CallContextFieldTag,
+    util::Expr,
+};
+use eth_types::{evm_types::OpcodeId, Field, ToLittleEndian, ToScalar};
+use halo2_proofs::{circuit::Value, plonk::Error};
+
+/// Gadget for a RETURNDATACOPY whose range `offset..offset + size` exceeds the
+/// return data of the last callee, which halts the current call.
+#[derive(Clone, Debug)]
+pub(crate) struct ErrorReturnDataOutOfBoundGadget<F> {
+    opcode: Cell<F>,
+    dest_offset: Word<F>,
+    data_offset: Word<F>,
+    size: Word<F>,
+    return_data_length: Cell<F>,
+    /// Check if all but the first 8 bytes of `data_offset` and `size` are
+    /// zero, otherwise `offset + size` is out of bound for sure.
+    high_bytes_are_zero: IsZeroGadget<F>,
+    /// Check if `return_data_length < offset + size` on their first 8 bytes.
+    /// The sum is below 2^65, so 9 bytes are sufficient.
+    return_data_length_lt_end: LtGadget<F, 9>,
+    restore_context: RestoreContextGadget<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for ErrorReturnDataOutOfBoundGadget<F> {
+    const NAME: &'static str = "ErrorReturnDataOutOfBound";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::ErrorReturnDataOutOfBound;
+
+    fn configure(cb: &mut ConstraintBuilder<F>) -> Self {
+        let opcode = cb.query_cell();
+        cb.opcode_lookup(opcode.expr(), 1.expr());
+        cb.require_equal(
+            "ErrorReturnDataOutOfBound only happens in RETURNDATACOPY",
+            opcode.expr(),
+            OpcodeId::RETURNDATACOPY.expr(),
+        );
+
+        let dest_offset = cb.query_word();
+        let data_offset = cb.query_word();
+        let size = cb.query_word();
+
+        // Pop dest_offset, offset, length from stack
+        cb.stack_pop(dest_offset.expr());
+        cb.stack_pop(data_offset.expr());
+        cb.stack_pop(size.expr());
+
+        let return_data_length =
+            cb.call_context(None, CallContextFieldTag::LastCalleeReturnDataLength);
+
+        // Check offset + size > return_data_length
+        let high_bytes_are_zero = IsZeroGadget::construct(
+            cb,
+            sum::expr(data_offset.cells[8..].iter().chain(size.cells[8..].iter())),
+        );
+        let return_data_length_lt_end = LtGadget::construct(
+            cb,
+            return_data_length.expr(),
+            from_bytes::expr(&data_offset.cells[..8]) + from_bytes::expr(&size.cells[..8]),
+        );
+        cb.require_zero(
+            "offset + size > return_data_length",
+            high_bytes_are_zero.expr() * (1.expr() - return_data_length_lt_end.expr()),
+        );
+
+        // Current call must fail.
+        cb.call_context_lookup(false.expr(), None, CallContextFieldTag::IsSuccess, 0.expr());
+
+        // Go to EndTx only when is_root
+        let is_to_end_tx = cb.next.execution_state_selector([ExecutionState::EndTx]);
+        cb.require_equal(
+            "Go to EndTx only when is_root",
+            cb.curr.state.is_root.expr(),
+            is_to_end_tx,
+        );
+
+        // When it's a root call
+        cb.condition(cb.curr.state.is_root.expr(), |cb| {
+            // Do step state transition
+            cb.require_step_state_transition(StepStateTransition {
+                call_id: Same,
+                rw_counter: Delta(5.expr() + cb.curr.state.reversible_write_counter.expr()),
+                ..StepStateTransition::any()
+            });
+        });
+
+        // When it's an internal call, need to restore caller's state as finishing this
+        // call. Restore caller state to next StepState
+        let restore_context = cb.condition(1.expr() - cb.curr.state.is_root.expr(), |cb| {
+            RestoreContextGadget::construct(
+                cb,
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                0.expr(),
+            )
+        });
+
+        Self {
+            opcode,
+            dest_offset,
+            data_offset,
+            size,
+            return_data_length,
+            high_bytes_are_zero,
+            return_data_length_lt_end,
+            restore_context,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block<F>,
+        _: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        let opcode = step.opcode.unwrap();
+        self.opcode
+            .assign(region, offset, Value::known(F::from(opcode.as_u64())))?;
+
+        let [dest_offset, data_offset, size] =
+            [0, 1, 2].map(|idx| block.rws[step.rw_indices[idx]].stack_value());
+        self.dest_offset
+            .assign(region, offset, Some(dest_offset.to_le_bytes()))?;
+        self.data_offset
+            .assign(region, offset, Some(data_offset.to_le_bytes()))?;
+        self.size.assign(region, offset, Some(size.to_le_bytes()))?;
+
+        let return_data_length = block.rws[step.rw_indices[3]].call_context_value();
+        self.return_data_length.assign(
+            region,
+            offset,
+            Value::known(
+                return_data_length
+                    .to_scalar()
+                    .expect("unexpected U256 -> Scalar conversion failure"),
+            ),
+        )?;
+
+        let high_bytes_sum = data_offset.to_le_bytes()[8..]
+            .iter()
+            .chain(size.to_le_bytes()[8..].iter())
+            .fold(0, |acc, byte| acc + *byte as u64);
+        self.high_bytes_are_zero
+            .assign(region, offset, F::from(high_bytes_sum))?;
+        self.return_data_length_lt_end.assign(
+            region,
+            offset,
+            F::from(return_data_length.low_u64()),
+            F::from(data_offset.low_u64()) + F::from(size.low_u64()),
+        )?;
+
+        self.restore_context
+            .assign(region, offset, block, call, step, 5)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_util::run_test_circuits;
+    use bus_mapping::evm::OpcodeId;
+    use eth_types::{bytecode, ToWord, Word};
+    use mock::TestContext;
+
+    fn test_root(return_data_size: usize, offset: Word, size: Word) {
+        let (addr_a, addr_b) = (mock::MOCK_ACCOUNTS[0], mock::MOCK_ACCOUNTS[1]);
+
+        let code_b = bytecode! {
+            PUSH32(return_data_size)
+            PUSH1(0x00)
+            RETURN
+        };
+        // code A calls code B, then copies past the end of its return data.
+        let code_a = bytecode! {
+            PUSH32(return_data_size) // retLength
+            PUSH1(0x00) // retOffset
+            PUSH1(0x00) // argsLength
+            PUSH1(0x00) // argsOffset
+            PUSH1(0x00) // value
+            PUSH32(addr_b.to_word()) // addr
+            PUSH32(0x1_0000) // gas
+            CALL
+            PUSH32(size) // size
+            PUSH32(offset) // offset
+            PUSH1(0x00) // dest_offset
+            RETURNDATACOPY
+            STOP
+        };
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(addr_a).code(code_a);
+                accs[1].address(addr_b).code(code_b);
+                accs[2]
+                    .address(mock::MOCK_ACCOUNTS[2])
+                    .balance(Word::from(1u64 << 30));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[2].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap();
+
+        assert_eq!(run_test_circuits(ctx, None), Ok(()));
+    }
+
+    fn test_internal(offset: Word, size: Word) {
+        let (addr_a, addr_b) = (mock::MOCK_ACCOUNTS[0], mock::MOCK_ACCOUNTS[1]);
+
+        // code B has made no call, so its return data buffer is empty.
+        let code_b = bytecode! {
+            PUSH32(size) // size
+            PUSH32(offset) // offset
+            PUSH1(0x00) // dest_offset
+            RETURNDATACOPY
+            STOP
+        };
+        let code_a = bytecode! {
+            PUSH1(0x00) // retLength
+            PUSH1(0x00) // retOffset
+            PUSH1(0x00) // argsLength
+            PUSH1(0x00) // argsOffset
+            PUSH1(0x00) // value
+            PUSH32(addr_b.to_word()) // addr
+            PUSH32(0x1_0000) // gas
+            CALL
+            STOP
+        };
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(addr_a).code(code_a);
+                accs[1].address(addr_b).code(code_b);
+                accs[2]
+                    .address(mock::MOCK_ACCOUNTS[2])
+                    .balance(Word::from(1u64 << 30));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[2].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap();
+
+        let step = ctx.geth_traces[0]
+            .struct_logs
+            .iter()
+            .find(|step| step.op == OpcodeId::RETURNDATACOPY)
+            .unwrap();
+        assert_eq!(step.depth, 2);
+        assert_eq!(run_test_circuits(ctx, None), Ok(()));
+    }
+
+    #[test]
+    fn returndatacopy_out_of_bound_root() {
+        test_root(0x20, Word::from(0x10), Word::from(0x11));
+        test_root(0x20, Word::from(0x21), Word::zero());
+        // offset + size overflows u64, or offset itself doesn't fit in it.
+        test_root(0x20, Word::from(u64::MAX), Word::one());
+        test_root(0x20, Word::MAX, Word::zero());
+    }
+
+    #[test]
+    fn returndatacopy_out_of_bound_internal() {
+        test_internal(Word::zero(), Word::one());
+        test_internal(Word::MAX, Word::zero());
+    }
+}
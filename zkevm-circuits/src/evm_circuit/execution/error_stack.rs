@@ -209,7 +209,7 @@ mod test {
     }
 
     fn test_stack_overflow(opcode: OpcodeId, bytes: &[u8]) {
-        assert!(bytes.len() == opcode.data_len());
+        assert!(bytes.len() == opcode.push_data_len());
         let mut bytecode = bytecode! {
             .write_op(opcode)
         };
@@ -304,4 +304,12 @@ mod tests {
         test_ok(Word::MAX, 2.into());
         test_ok(Word::MAX, 3.into());
     }
+
+    #[test]
+    fn exp_gadget_wrapping() {
+        // 2^256 wraps to 0 and 3^(2^256 - 1) runs a squaring step per exponent bit.
+        test_ok(2.into(), 256.into());
+        test_ok(2.into(), 257.into());
+        test_ok(3.into(), Word::MAX);
+    }
 }
@@ -0,0 +1,396 @@
+use bus_mapping::circuit_input_builder::CopyDataType;
+use eth_types::{evm_types::GasCost, Field, ToAddress, ToLittleEndian, ToScalar};
+use halo2_proofs::{circuit::Value, plonk::Error};
+
+use crate::{
+    evm_circuit::{
+        param::{N_BYTES_ACCOUNT_ADDRESS, N_BYTES_MEMORY_ADDRESS, N_BYTES_MEMORY_WORD_SIZE},
+        step::ExecutionState,
+        util::{
+            common_gadget::SameContextGadget,
+            constraint_builder::{
+                ConstraintBuilder, ReversionInfo, StepStateTransition, Transition,
+            },
+            from_bytes,
+            memory_gadget::{MemoryAddressGadget, MemoryCopierGasGadget, MemoryExpansionGadget},
+            not, select, CachedRegion, Cell, MemoryAddress, RandomLinearCombination,
+        },
+        witness::{Block, Call, ExecStep, Rw, Transaction},
+    },
+    table::{AccountFieldTag, CallContextFieldTag},
+    util::Expr,
+};
+
+use super::ExecutionGadget;
+
+#[derive(Clone, Debug)]
+pub(crate) struct ExtcodecopyGadget<F> {
+    same_context: SameContextGadget<F>,
+    /// Address of the account whose code is copied.
+    external_address: RandomLinearCombination<F, N_BYTES_ACCOUNT_ADDRESS>,
+    tx_id: Cell<F>,
+    reversion_info: ReversionInfo<F>,
+    is_warm: Cell<F>,
+    /// A non-existing account is copied as empty code.
+    exists: Cell<F>,
+    /// Code hash of the external account, zero if it doesn't exist.
+    code_hash: Cell<F>,
+    /// Holds the memory address for the offset in code from where we read.
+    code_offset: MemoryAddress<F>,
+    /// Holds the size of the external account's bytecode.
+    code_size: Cell<F>,
+    /// The external code is copied to memory. To verify this copy operation we
+    /// need the MemoryAddressGadget.
+    dst_memory_addr: MemoryAddressGadget<F>,
+    /// Opcode EXTCODECOPY has a dynamic gas cost:
+    /// gas_code = access_cost + copy_cost * minimum_word_size +
+    /// memory_expansion_cost
+    memory_expansion: MemoryExpansionGadget<F, 1, N_BYTES_MEMORY_WORD_SIZE>,
+    memory_copier_gas: MemoryCopierGasGadget<F, { GasCost::COPY }>,
+    /// RW inverse counter from the copy table at the start of related copy
+    /// steps.
+    copy_rwc_inc: Cell<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for ExtcodecopyGadget<F> {
+    const NAME: &'static str = "EXTCODECOPY";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::EXTCODECOPY;
+
+    fn configure(cb: &mut ConstraintBuilder<F>) -> Self {
+        let opcode = cb.query_cell();
+
+        // Query elements to be popped from the stack.
+        let external_address = cb.query_rlc();
+        let dst_memory_offset = cb.query_cell();
+        let code_offset = cb.query_rlc();
+        let size = cb.query_rlc();
+
+        // Pop items from stack.
+        cb.stack_pop(external_address.expr());
+        cb.stack_pop(dst_memory_offset.expr());
+        cb.stack_pop(code_offset.expr());
+        cb.stack_pop(size.expr());
+
+        let tx_id = cb.call_context(None, CallContextFieldTag::TxId);
+        let mut reversion_info = cb.reversion_info_read(None);
+        let is_warm = cb.query_bool();
+        cb.account_access_list_write(
+            tx_id.expr(),
+            from_bytes::expr(&external_address.cells),
+            1.expr(),
+            is_warm.expr(),
+            Some(&mut reversion_info),
+        );
+
+        // Fetch the code hash of the external account and the length of its
+        // bytecode, which are zero if the account doesn't exist.
+        let exists = cb.query_bool();
+        let code_hash = cb.query_cell();
+        let code_size = cb.condition(exists.expr(), |cb| {
+            cb.account_read(
+                from_bytes::expr(&external_address.cells),
+                AccountFieldTag::CodeHash,
+                code_hash.expr(),
+            );
+            cb.bytecode_length(code_hash.expr())
+        });
+        cb.condition(not::expr(exists.expr()), |cb| {
+            cb.account_read(
+                from_bytes::expr(&external_address.cells),
+                AccountFieldTag::NonExisting,
+                0.expr(),
+            );
+            cb.require_zero("code_hash == 0 for non-existing account", code_hash.expr());
+        });
+
+        // Construct memory address in the destination (memory) to which we copy code.
+        let dst_memory_addr = MemoryAddressGadget::construct(cb, dst_memory_offset, size);
+
+        // Calculate the next memory size and the gas cost for this memory
+        // access. This also accounts for the dynamic gas required to copy bytes to
+        // memory.
+        let memory_expansion = MemoryExpansionGadget::construct(cb, [dst_memory_addr.address()]);
+        let memory_copier_gas = MemoryCopierGasGadget::construct(
+            cb,
+            dst_memory_addr.length(),
+            memory_expansion.gas_cost(),
+        );
+
+        let copy_rwc_inc = cb.query_cell();
+        cb.condition(dst_memory_addr.has_length(), |cb| {
+            cb.copy_table_lookup(
+                code_hash.expr(),
+                CopyDataType::Bytecode.expr(),
+                cb.curr.state.call_id.expr(),
+                CopyDataType::Memory.expr(),
+                from_bytes::expr(&code_offset.cells),
+                select::expr(exists.expr(), code_size.expr(), 0.expr()),
+                dst_memory_addr.offset(),
+                dst_memory_addr.length(),
+                0.expr(), // for EXTCODECOPY, rlc_acc is 0
+                copy_rwc_inc.expr(),
+            );
+        });
+        cb.condition(not::expr(dst_memory_addr.has_length()), |cb| {
+            cb.require_zero(
+                "if no bytes to copy, copy table rwc inc == 0",
+                copy_rwc_inc.expr(),
+            );
+        });
+
+        let gas_cost = select::expr(
+            is_warm.expr(),
+            GasCost::WARM_ACCESS.expr(),
+            GasCost::COLD_ACCOUNT_ACCESS.expr(),
+        ) + memory_copier_gas.gas_cost();
+
+        // Expected state transition.
+        let step_state_transition = StepStateTransition {
+            rw_counter: Transition::Delta(cb.rw_counter_offset()),
+            program_counter: Transition::Delta(1.expr()),
+            stack_pointer: Transition::Delta(4.expr()),
+            memory_word_size: Transition::To(memory_expansion.next_memory_word_size()),
+            gas_left: Transition::Delta(-gas_cost),
+            reversible_write_counter: Transition::Delta(1.expr()),
+            ..Default::default()
+        };
+        let same_context = SameContextGadget::construct(cb, opcode, step_state_transition);
+
+        Self {
+            same_context,
+            external_address,
+            tx_id,
+            reversion_info,
+            is_warm,
+            exists,
+            code_hash,
+            code_offset,
+            code_size,
+            dst_memory_addr,
+            memory_expansion,
+            memory_copier_gas,
+            copy_rwc_inc,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block<F>,
+        tx: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        self.same_context.assign_exec_step(region, offset, step)?;
+
+        let [external_address, dest_offset, code_offset, size] =
+            [0, 1, 2, 3].map(|i| block.rws[step.rw_indices[i]].stack_value());
+
+        let mut address_bytes = external_address.to_address().0;
+        address_bytes.reverse();
+        self.external_address
+            .assign(region, offset, Some(address_bytes))?;
+        self.code_offset.assign(
+            region,
+            offset,
+            Some(
+                code_offset.to_le_bytes()[..N_BYTES_MEMORY_ADDRESS]
+                    .try_into()
+                    .unwrap(),
+            ),
+        )?;
+
+        self.tx_id
+            .assign(region, offset, Value::known(F::from(tx.id as u64)))?;
+        self.reversion_info.assign(
+            region,
+            offset,
+            call.rw_counter_end_of_reversion,
+            call.is_persistent,
+        )?;
+
+        let (_, is_warm) = block.rws[step.rw_indices[7]].tx_access_list_value_pair();
+        self.is_warm
+            .assign(region, offset, Value::known(F::from(is_warm)))?;
+
+        let (exists, code_hash) = match block.rws[step.rw_indices[8]] {
+            Rw::Account {
+                field_tag: AccountFieldTag::CodeHash,
+                value,
+                ..
+            } => (true, value),
+            Rw::Account {
+                field_tag: AccountFieldTag::NonExisting,
+                ..
+            } => (false, 0.into()),
+            _ => unreachable!(),
+        };
+        let code_size = if exists {
+            block
+                .bytecodes
+                .get(&code_hash)
+                .expect("could not find external bytecode")
+                .bytes
+                .len()
+        } else {
+            0
+        };
+
+        self.exists
+            .assign(region, offset, Value::known(F::from(exists)))?;
+        self.code_hash.assign(
+            region,
+            offset,
+            Value::known(RandomLinearCombination::random_linear_combine(
+                code_hash.to_le_bytes(),
+                block.randomness,
+            )),
+        )?;
+        self.code_size
+            .assign(region, offset, Value::known(F::from(code_size as u64)))?;
+
+        // assign the destination memory offset.
+        let memory_address =
+            self.dst_memory_addr
+                .assign(region, offset, dest_offset, size, block.randomness)?;
+
+        // assign to gadgets handling memory expansion cost and copying cost.
+        let (_, memory_expansion_cost) = self.memory_expansion.assign(
+            region,
+            offset,
+            step.memory_word_size(),
+            [memory_address],
+        )?;
+        self.memory_copier_gas
+            .assign(region, offset, size.as_u64(), memory_expansion_cost)?;
+        // rw_counter increase from copy table lookup is number of bytes copied.
+        self.copy_rwc_inc.assign(
+            region,
+            offset,
+            Value::known(
+                size.to_scalar()
+                    .expect("unexpected U256 -> Scalar conversion failure"),
+            ),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::evm_circuit::{
+        test::{run_test_circuit, witness_block_geth_data_default},
+        witness::Rw,
+    };
+    use crate::test_util::run_test_circuits;
+    use eth_types::{bytecode, evm_types::OpcodeId, Bytecode, Bytes, ToWord, Word};
+    use mock::{TestContext, MOCK_1_ETH, MOCK_ACCOUNTS, MOCK_CODES};
+
+    /// Copy `size` bytes of the code of an external account, which doesn't
+    /// exist if `external_code` is `None`, from `code_offset` to
+    /// `memory_offset`.
+    fn test_ctx(
+        external_code: Option<Bytes>,
+        memory_offset: usize,
+        code_offset: usize,
+        size: usize,
+        is_warm: bool,
+    ) -> TestContext<3, 1> {
+        let external_address = MOCK_ACCOUNTS[4];
+        let mut code = Bytecode::default();
+        if is_warm {
+            code.append(&bytecode! {
+                PUSH20(external_address.to_word())
+                EXTCODESIZE
+                POP
+            });
+        }
+        code.append(&bytecode! {
+            PUSH32(Word::from(size))
+            PUSH32(Word::from(code_offset))
+            PUSH32(Word::from(memory_offset))
+            PUSH20(external_address.to_word())
+            EXTCODECOPY
+            STOP
+        });
+
+        TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(MOCK_ACCOUNTS[0]).code(code);
+                accs[1].address(MOCK_ACCOUNTS[1]).balance(*MOCK_1_ETH);
+                match external_code {
+                    Some(external_code) => {
+                        accs[2].address(external_address).code(external_code);
+                    }
+                    None => {
+                        accs[2].address(MOCK_ACCOUNTS[2]).balance(*MOCK_1_ETH);
+                    }
+                }
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[1].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+    }
+
+    fn test_ok(
+        external_code: Option<Bytes>,
+        memory_offset: usize,
+        code_offset: usize,
+        size: usize,
+        is_warm: bool,
+    ) {
+        let ctx = test_ctx(external_code, memory_offset, code_offset, size, is_warm);
+        assert_eq!(run_test_circuits(ctx, None), Ok(()));
+    }
+
+    #[test]
+    fn extcodecopy_gadget_cold_and_warm() {
+        let code = MOCK_CODES[4].clone();
+        test_ok(Some(code.clone()), 0x00, 0x00, 0x06, false);
+        test_ok(Some(code.clone()), 0x20, 0x01, 0x03, true);
+        test_ok(Some(code), 0x00, 0x00, 0x00, false);
+    }
+
+    #[test]
+    fn extcodecopy_gadget_non_existing_account() {
+        test_ok(None, 0x00, 0x00, 0x20, false);
+        test_ok(None, 0x40, 0x10, 0x20, true);
+    }
+
+    #[test]
+    fn extcodecopy_gadget_zero_padding() {
+        let code = MOCK_CODES[4].clone();
+        test_ok(Some(code.clone()), 0x00, 0x01, 0x40, false);
+        test_ok(Some(code.clone()), 0x10, 0x100, 0x20, true);
+
+        let ctx = test_ctx(Some(code.clone()), 0x00, 0x01, 0x40, false);
+        let block = witness_block_geth_data_default(ctx.into());
+
+        // Everything past the end of the external code is copied as zeros.
+        let copy_event = &block.copy_events[0];
+        assert_eq!(copy_event.src_addr_end as usize, code.len());
+        assert_eq!(copy_event.bytes.len(), 0x40);
+        for (idx, (value, _)) in copy_event.bytes.iter().enumerate() {
+            assert_eq!(*value, code.get(1 + idx).copied().unwrap_or(0));
+        }
+
+        // Cold account access, and copying 2 words that expand the memory by 2
+        // words.
+        let step = block.txs[0]
+            .steps
+            .iter()
+            .find(|step| step.opcode == Some(OpcodeId::EXTCODECOPY))
+            .unwrap();
+        assert_eq!(step.gas_cost, 2600 + 2 * 3 + 2 * 3);
+        assert!(matches!(block.rws[step.rw_indices[8]], Rw::Account { .. }));
+
+        run_test_circuit(block).unwrap();
+    }
+}
@@ -178,9 +178,13 @@ impl<F: Field> ExecutionGadget<F> for ExtcodehashGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::test_circuits_block_geth_data_default;
+    use crate::{
+        evm_circuit::test::{pushed_values, run_test_circuit, witness_block_with_bytecode},
+        test_util::test_circuits_block_geth_data_default,
+    };
     use eth_types::{
         address, bytecode,
+        evm_types::{GasCost, OpcodeId},
         geth_types::{Account, GethData},
         Address, Bytecode, Bytes, ToWord, Word, U256,
     };
@@ -246,6 +250,42 @@ mod test {
         test_circuits_block_geth_data_default(block).unwrap();
     }
 
+    #[test]
+    fn ext_account_cold_then_warm_non_existing() {
+        // EXTERNAL_ADDRESS isn't one of the accounts of the context.
+        let code = bytecode! {
+            PUSH20(EXTERNAL_ADDRESS.to_word())
+            BALANCE
+            PUSH20(EXTERNAL_ADDRESS.to_word())
+            EXTCODESIZE
+            PUSH20(EXTERNAL_ADDRESS.to_word())
+            EXTCODEHASH
+            STOP
+        };
+        let block = witness_block_with_bytecode(code);
+
+        let steps = pushed_values(&block, |step| {
+            matches!(
+                step.opcode,
+                Some(OpcodeId::BALANCE | OpcodeId::EXTCODESIZE | OpcodeId::EXTCODEHASH)
+            )
+        })
+        .into_iter()
+        .map(|(step, pushed)| (step.gas_cost, pushed))
+        .collect::<Vec<_>>();
+        // Only the first access is cold, and all push zero, the code hash
+        // included.
+        assert_eq!(
+            steps,
+            vec![
+                (GasCost::COLD_ACCOUNT_ACCESS.as_u64(), Word::zero()),
+                (GasCost::WARM_ACCESS.as_u64(), Word::zero()),
+                (GasCost::WARM_ACCESS.as_u64(), Word::zero()),
+            ]
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
+
     #[test]
     fn extcodehash_warm_empty_account() {
         test_ok(None, true);
@@ -87,7 +87,11 @@ impl<F: Field> ExecutionGadget<F> for GasGadget<F> {
 #[cfg(test)]
 mod test {
     use crate::{
-        evm_circuit::{test::run_test_circuit, witness::block_convert},
+        evm_circuit::{
+            step::ExecutionState,
+            test::{pushed_values, run_test_circuit, witness_block_with_bytecode},
+            witness::block_convert,
+        },
         test_util::{run_test_circuits, BytecodeTestConfig},
     };
     use bus_mapping::mock::BlockData;
@@ -161,4 +165,23 @@ mod test {
         block.txs[0].steps[2].gas_left -= 1;
         assert!(run_test_circuit(block).is_err());
     }
+
+    #[test]
+    fn gas_gadget_pushes_gas_left() {
+        let bytecode = bytecode! {
+            GAS
+            GAS
+            STOP
+        };
+        let block = witness_block_with_bytecode(bytecode);
+
+        let pushed = pushed_values(&block, |step| step.execution_state == ExecutionState::GAS);
+        assert_eq!(pushed.len(), 2);
+        for (step, value) in pushed {
+            // The pushed gas is what's left after paying for GAS itself.
+            assert_eq!(step.gas_cost, 2);
+            assert_eq!(value, Word::from(step.gas_left - step.gas_cost));
+        }
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
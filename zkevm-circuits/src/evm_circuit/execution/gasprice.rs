@@ -94,8 +94,11 @@ impl<F: Field> ExecutionGadget<F> for GasPriceGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
-    use eth_types::{bytecode, Word};
+    use crate::{
+        evm_circuit::test::{pushed_values, run_test_circuit, witness_block_geth_data_default},
+        test_util::run_test_circuits,
+    };
+    use eth_types::{bytecode, evm_types::OpcodeId, Word};
     use mock::test_ctx::{helpers::*, TestContext};
 
     #[test]
@@ -124,4 +127,39 @@ mod test {
 
         assert_eq!(run_test_circuits(ctx, None), Ok(()));
     }
+
+    #[test]
+    fn gasprice_gadget_pushes_tx_gas_price() {
+        let bytecode = bytecode! {
+            GASPRICE
+            STOP
+        };
+        let gas_price = Word::from(3_000_000_007u64);
+        let block = witness_block_geth_data_default(
+            TestContext::<2, 1>::new(
+                None,
+                account_0_code_account_1_no_code(bytecode),
+                |mut txs, accs| {
+                    txs[0]
+                        .from(accs[1].address)
+                        .to(accs[0].address)
+                        .gas_price(gas_price);
+                },
+                |block, _tx| block,
+            )
+            .unwrap()
+            .into(),
+        );
+
+        let tx = &block.txs[0];
+        assert_eq!(tx.gas_price, gas_price);
+        let (step, pushed) =
+            pushed_values(&block, |step| step.opcode == Some(OpcodeId::GASPRICE))[0];
+        assert_eq!(pushed, tx.gas_price);
+        assert_eq!(
+            step.gas_cost,
+            OpcodeId::GASPRICE.constant_gas_cost().as_u64()
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -145,6 +145,12 @@ mod test {
         test_ok(100);
     }
 
+    #[test]
+    fn jump_gadget_invalid_destination() {
+        test_invalid_jump(34);
+        test_invalid_jump(100);
+    }
+
     #[test]
     #[ignore]
     fn jump_gadget_huge_bytecode() {
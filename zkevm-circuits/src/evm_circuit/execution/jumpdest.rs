@@ -53,7 +53,13 @@ impl<F: Field> ExecutionGadget<F> for JumpdestGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
+    use crate::{
+        evm_circuit::{
+            step::ExecutionState,
+            test::{run_test_circuit, witness_block_with_bytecode},
+        },
+        test_util::run_test_circuits,
+    };
     use eth_types::bytecode;
     use mock::TestContext;
 
@@ -72,4 +78,28 @@ mod test {
             Ok(())
         );
     }
+
+    #[test]
+    fn jumpdest_gadget_advances_pc() {
+        let bytecode = bytecode! {
+            JUMPDEST
+            JUMPDEST
+            STOP
+        };
+        let block = witness_block_with_bytecode(bytecode);
+
+        let steps = &block.txs[0].steps;
+        let jumpdests = steps
+            .windows(2)
+            .filter(|pair| pair[0].execution_state == ExecutionState::JUMPDEST)
+            .collect::<Vec<_>>();
+        assert_eq!(jumpdests.len(), 2);
+        for pair in jumpdests {
+            assert_eq!(pair[1].program_counter, pair[0].program_counter + 1);
+            assert_eq!(pair[0].gas_left - pair[1].gas_left, 1);
+            assert_eq!(pair[1].stack_pointer, pair[0].stack_pointer);
+            assert!(pair[0].rw_indices.is_empty());
+        }
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -158,6 +158,25 @@ mod test {
         test_ok(1 << 11, 0.into());
     }
 
+    #[test]
+    fn jumpi_gadget_not_taken_invalid_destination() {
+        // The destination is only looked up when the jump is taken.
+        let bytecode = bytecode! {
+            PUSH32(Word::zero())
+            PUSH32(Word::from(100))
+            JUMPI
+            STOP
+        };
+
+        assert_eq!(
+            run_test_circuits(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                None
+            ),
+            Ok(())
+        );
+    }
+
     #[test]
     #[ignore]
     fn jumpi_gadget_huge_bytecode() {
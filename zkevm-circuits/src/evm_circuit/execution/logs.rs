@@ -264,11 +264,19 @@ impl<F: Field> ExecutionGadget<F> for LogGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use eth_types::{evm_types::OpcodeId, Bytecode, Word};
+    use eth_types::{evm_types::OpcodeId, Bytecode, ToBigEndian, ToWord, Word};
+    use itertools::Itertools;
     use mock::TestContext;
     use rand::Rng;
 
-    use crate::test_util::run_test_circuits;
+    use crate::{
+        evm_circuit::{
+            test::{run_test_circuit, witness_block_with_bytecode},
+            witness::Rw,
+        },
+        table::{RwTableTag, TxLogFieldTag},
+        test_util::run_test_circuits,
+    };
 
     #[test]
     fn log_gadget_simple() {
@@ -331,6 +339,52 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn log_gadget_rows_in_order() {
+        let data = Word::from_big_endian(&(1..=32).collect::<Vec<u8>>());
+        let topics = [0xA0, 0xef, 0xb0, 0x37].map(Word::from);
+        // LOG0 and LOG4 of the same 32 bytes of data.
+        let mut code = prepare_code(&data.to_be_bytes(), 0);
+        for topic_count in [0, 4] {
+            for topic in topics[..topic_count].iter().rev() {
+                code.push(32, *topic);
+            }
+            code.push(1, Word::from(0x20));
+            code.push(1, Word::zero());
+            code.write_op(OpcodeId::LOG_ALL[topic_count]);
+        }
+        code.write_op(OpcodeId::STOP);
+        let block = witness_block_with_bytecode(code);
+
+        let rows = block.rws.0[&RwTableTag::TxLog]
+            .iter()
+            .sorted_by_key(|rw| rw.rw_counter())
+            .map(|rw| match rw {
+                Rw::TxLog {
+                    log_id,
+                    field_tag,
+                    index,
+                    value,
+                    ..
+                } => (*log_id, *field_tag, *index, *value),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        let address = block.txs[0].callee_address;
+        let mut expected = Vec::new();
+        for (log_id, topic_count) in [(1, 0), (2, 4)] {
+            expected.push((log_id, TxLogFieldTag::Address, 0, address.to_word()));
+            for (index, topic) in topics[..topic_count].iter().enumerate() {
+                expected.push((log_id, TxLogFieldTag::Topic, index, *topic));
+            }
+            for (index, byte) in data.to_be_bytes().iter().enumerate() {
+                expected.push((log_id, TxLogFieldTag::Data, index, Word::from(*byte)));
+            }
+        }
+        assert_eq!(rows, expected);
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
+
     // test single log code and single copy log step
     fn test_log_ok(topics: &[Word], is_persistent: bool) {
         let mut pushdata = [0u8; 320];
@@ -245,6 +245,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn memory_gadget_store_load_round_trip() {
+        let value = Word::from_big_endian(&(1..33).collect::<Vec<_>>());
+        for (store, address) in [
+            (OpcodeId::MSTORE, Word::from(0x40)),
+            (OpcodeId::MSTORE, Word::from(0x41)),
+            (OpcodeId::MSTORE8, Word::from(0x5f)),
+        ] {
+            // The stored bytes are read back big-endian, MSTORE8 only storing
+            // the least significant byte at the last loaded position.
+            let bytecode = bytecode! {
+                PUSH32(value)
+                PUSH32(address)
+                .write_op(store)
+                PUSH32(Word::from(0x40))
+                MLOAD
+                STOP
+            };
+
+            assert_eq!(
+                run_test_circuits(
+                    TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                    None
+                ),
+                Ok(())
+            );
+        }
+    }
+
     #[test]
     fn memory_gadget_rand() {
         let calc_gas_cost = |opcode, memory_address: Word| {
@@ -79,7 +79,13 @@ impl<F: Field> ExecutionGadget<F> for MsizeGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
+    use crate::{
+        evm_circuit::{
+            step::ExecutionState,
+            test::{pushed_values, run_test_circuit, witness_block_with_bytecode},
+        },
+        test_util::run_test_circuits,
+    };
     use eth_types::{bytecode, Word};
     use mock::TestContext;
 
@@ -103,4 +109,28 @@ mod test {
             Ok(())
         );
     }
+
+    #[test]
+    fn msize_gadget_pushes_memory_size() {
+        let bytecode = bytecode! {
+            MSIZE
+            PUSH1(0x01)
+            PUSH1(0x30)
+            MSTORE8
+            MSIZE
+            STOP
+        };
+        let block = witness_block_with_bytecode(bytecode);
+
+        // The memory size is rounded up to 0x40 after writing at 0x30.
+        let pushed = pushed_values(&block, |step| step.execution_state == ExecutionState::MSIZE)
+            .into_iter()
+            .map(|(step, value)| (Word::from(step.memory_size), value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pushed,
+            vec![(0.into(), 0.into()), (0x40.into(), 0x40.into())]
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -189,6 +189,21 @@ mod test {
         test_ok(OpcodeId::MUL, a, b);
     }
 
+    #[test]
+    fn mul_gadget_full_width() {
+        // (2**256 - 1)**2 = 1 (mod 2**256)
+        test_ok(OpcodeId::MUL, Word::MAX, Word::MAX);
+        test_ok(OpcodeId::MUL, Word::MAX, 2.into());
+    }
+
+    #[test]
+    fn div_mod_gadget_by_zero() {
+        test_ok(OpcodeId::DIV, Word::MAX, 0.into());
+        test_ok(OpcodeId::MOD, Word::MAX, 0.into());
+        test_ok(OpcodeId::DIV, 0.into(), 0.into());
+        test_ok(OpcodeId::MOD, 0.into(), 0.into());
+    }
+
     #[test]
     fn mul_gadget_rand() {
         let a = rand_word();
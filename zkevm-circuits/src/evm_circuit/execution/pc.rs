@@ -77,8 +77,14 @@ impl<F: Field> ExecutionGadget<F> for PcGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
-    use eth_types::bytecode;
+    use crate::{
+        evm_circuit::{
+            step::ExecutionState,
+            test::{pushed_values, run_test_circuit, witness_block_with_bytecode},
+        },
+        test_util::run_test_circuits,
+    };
+    use eth_types::{bytecode, Word};
     use mock::TestContext;
 
     fn test_ok() {
@@ -101,4 +107,22 @@ mod test {
     fn pc_gadget_simple() {
         test_ok();
     }
+
+    #[test]
+    fn pc_gadget_pushes_program_counter() {
+        let bytecode = bytecode! {
+            PC
+            PUSH1(0)
+            PC
+            STOP
+        };
+        let block = witness_block_with_bytecode(bytecode);
+
+        let pushed = pushed_values(&block, |step| step.execution_state == ExecutionState::PC)
+            .into_iter()
+            .map(|(step, value)| (Word::from(step.program_counter), value))
+            .collect::<Vec<_>>();
+        assert_eq!(pushed, vec![(0.into(), 0.into()), (3.into(), 3.into())]);
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -106,6 +106,28 @@ mod test {
         test_ok(rand_word());
     }
 
+    #[test]
+    fn pop_gadget_sequence() {
+        // Each POP increases the stack pointer by one until the stack is empty.
+        let bytecode = bytecode! {
+            PUSH1(1)
+            PUSH2(0x0203)
+            PUSH32(Word::MAX)
+            POP
+            POP
+            POP
+            STOP
+        };
+
+        assert_eq!(
+            run_test_circuits(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                None
+            ),
+            Ok(())
+        );
+    }
+
     fn test_stack_underflow(value: Word) {
         let bytecode = bytecode! {
             PUSH32(value)
@@ -144,13 +144,20 @@ impl<F: Field> ExecutionGadget<F> for PushGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::{evm_circuit::test::rand_bytes, test_util::run_test_circuits};
+    use crate::{
+        evm_circuit::{
+            test::{pushed_values, rand_bytes, run_test_circuit, witness_block_with_bytecode},
+            witness::Rw,
+        },
+        test_util::run_test_circuits,
+    };
     use eth_types::bytecode;
     use eth_types::evm_types::OpcodeId;
+    use eth_types::Word;
     use mock::TestContext;
 
     fn test_ok(opcode: OpcodeId, bytes: &[u8]) {
-        assert!(bytes.len() == opcode.data_len());
+        assert!(bytes.len() == opcode.push_data_len());
 
         let mut bytecode = bytecode! {
             .write_op(opcode)
@@ -189,6 +196,30 @@ mod test {
         );
     }
 
+    #[test]
+    fn push_gadget_reads_push_data_at_next_pc() {
+        let bytecode = bytecode! {
+            PUSH1(0xab)
+            STOP
+        };
+        let block = witness_block_with_bytecode(bytecode);
+        let pushed = pushed_values(&block, |step| step.opcode == Some(OpcodeId::PUSH1));
+        assert_eq!(pushed.len(), 1);
+        let (step, value) = pushed[0];
+        assert_eq!(value, Word::from(0xab));
+        let push_idx = step.rw_indices[0];
+        assert_eq!(run_test_circuit(block.clone()), Ok(()));
+
+        // Pushing any other value than the byte at `pc + 1` is rejected by
+        // the bytecode lookup done by `opcode_lookup_at`.
+        let mut block = block;
+        let stack_rws = block.rws.0.get_mut(&push_idx.0).unwrap();
+        if let Rw::Stack { value, .. } = &mut stack_rws[push_idx.1] {
+            *value = Word::from(0xac);
+        }
+        assert!(run_test_circuit(block).is_err());
+    }
+
     #[test]
     #[ignore]
     fn push_gadget_rand() {
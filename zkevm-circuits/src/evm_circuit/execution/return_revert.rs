@@ -351,7 +351,14 @@ impl<F: Field> ExecutionGadget<F> for ReturnRevertGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
+    use crate::{
+        evm_circuit::{
+            test::{run_test_circuit, witness_block_geth_data_default},
+            witness::Rw,
+        },
+        table::RwTableTag,
+        test_util::run_test_circuits,
+    };
     use eth_types::{
         address, bytecode, evm_types::OpcodeId, geth_types::Account, Address, Bytecode, ToWord,
         Word,
@@ -609,4 +616,68 @@ mod test {
 
         assert_eq!(run_test_circuits(test_context, None), Ok(()),);
     }
+
+    #[test]
+    fn test_revert_rolls_back_sstore() {
+        let callee_code = bytecode! {
+            PUSH1(0x42) // value
+            PUSH1(0x00) // key
+            SSTORE
+            PUSH1(0x00) // length
+            PUSH1(0x00) // offset
+            REVERT
+        };
+        let caller_code = bytecode! {
+            PUSH1(0) // return data length
+            PUSH1(0) // return data offset
+            PUSH1(0) // call data length
+            PUSH1(0) // call data offset
+            PUSH1(0) // value
+            PUSH32(CALLEE_ADDRESS.to_word())
+            PUSH32(50000) // gas
+            CALL
+            STOP
+        };
+
+        let test_context = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x000000000000000000000000000000000000cafe"))
+                    .balance(eth(10));
+                accs[1].address(CALLER_ADDRESS).code(caller_code);
+                accs[2].address(CALLEE_ADDRESS).code(callee_code);
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .gas(100000u64.into());
+            },
+            |block, _| block,
+        )
+        .unwrap();
+        let block = witness_block_geth_data_default(test_context.into());
+
+        // The SSTORE is followed by the reversion write restoring the value.
+        let storage_writes = block.rws.0[&RwTableTag::AccountStorage]
+            .iter()
+            .sorted_by_key(|rw| rw.rw_counter())
+            .map(|rw| match rw {
+                Rw::AccountStorage {
+                    account_address,
+                    is_write: true,
+                    value,
+                    value_prev,
+                    ..
+                } if *account_address == CALLEE_ADDRESS => (*value, *value_prev),
+                _ => unreachable!("unexpected storage row {:?}", rw),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            storage_writes,
+            vec![(0x42.into(), Word::zero()), (Word::zero(), 0x42.into())]
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
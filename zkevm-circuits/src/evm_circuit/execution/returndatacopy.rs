@@ -380,13 +380,8 @@ mod test {
         test_ok_internal(0x200, 0x200, 0x200, 0x00, 0x150);
     }
 
-    // TODO: Add negative cases for out-of-bound and out-of-gas
-    // #[test]
-    // #[should_panic]
-    // fn returndatacopy_gadget_out_of_bound() {
-    //     test_ok_internal(0x00, 0x10, 0x20, 0x10, 0x10);
-    // }
-
+    // TODO: Add negative cases for out-of-gas, out-of-bound ones are in
+    // error_return_data_oob.rs
     // #[test]
     // #[should_panic]
     // fn returndatacopy_gadget_out_of_gas() {
@@ -0,0 +1,263 @@
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        step::ExecutionState,
+        table::{FixedTableTag, Lookup},
+        util::{
+            self,
+            common_gadget::SameContextGadget,
+            constraint_builder::{ConstraintBuilder, StepStateTransition, Transition::Delta},
+            from_bytes,
+            math_gadget::{IsZeroGadget, LtGadget, LtWordGadget, MulAddWordsGadget},
+            sum, CachedRegion,
+        },
+        witness::{Block, Call, ExecStep, Transaction},
+    },
+    util::Expr,
+};
+use bus_mapping::evm::OpcodeId;
+use eth_types::{Field, ToLittleEndian, WordBits, U256};
+use halo2_proofs::plonk::Error;
+
+/// SarGadget verifies opcode SAR.
+/// A negative `a` is shifted as `!(!a >> shift)`, so with
+/// `dividend = a < 0 ? !a : a` it verifies
+/// `quotient = dividend / (2^shift)` and `push = a < 0 ? !quotient :
+/// quotient`, where the quotient is 0 when shift >= 256.
+#[derive(Clone, Debug)]
+pub(crate) struct SarGadget<F> {
+    same_context: SameContextGadget<F>,
+    /// Shift word
+    shift: util::Word<F>,
+    /// Signed word that is shifted
+    a: util::Word<F>,
+    /// Shifted signed word
+    push: util::Word<F>,
+    quotient: util::Word<F>,
+    divisor: util::Word<F>,
+    remainder: util::Word<F>,
+    dividend: util::Word<F>,
+    /// Check if a is non-negative, i.e. its MSB < 2**7
+    sign_check: LtGadget<F, 1>,
+    /// Check if shift < 256, i.e. all but its first byte are zero
+    shift_hi_is_zero: IsZeroGadget<F>,
+    /// Gadget that verifies quotient * divisor + remainder = dividend
+    mul_add_words: MulAddWordsGadget<F>,
+    /// Check if divisor is zero
+    divisor_is_zero: IsZeroGadget<F>,
+    /// Check if remainder < divisor when divisor != 0
+    remainder_lt_divisor: LtWordGadget<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for SarGadget<F> {
+    const NAME: &'static str = "SAR";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::SAR;
+
+    fn configure(cb: &mut ConstraintBuilder<F>) -> Self {
+        let opcode = cb.query_cell();
+
+        let shift = cb.query_word();
+        let a = cb.query_word();
+        let push = cb.query_word();
+        let quotient = cb.query_word();
+        let divisor = cb.query_word();
+        let remainder = cb.query_word();
+        let dividend = cb.query_word();
+
+        cb.stack_pop(shift.expr());
+        cb.stack_pop(a.expr());
+        cb.stack_push(push.expr());
+
+        let sign_check = LtGadget::construct(cb, a.cells[31].expr(), 128.expr());
+        let is_neg = 1.expr() - sign_check.expr();
+
+        // Bitwise negate a for a negative a, and the quotient back.
+        for (a_byte, dividend_byte) in a.cells.iter().zip(dividend.cells.iter()) {
+            cb.require_equal(
+                "dividend == (a < 0 ? !a : a)",
+                dividend_byte.expr(),
+                a_byte.expr() + is_neg.clone() * (255.expr() - 2.expr() * a_byte.expr()),
+            );
+        }
+        for (quotient_byte, push_byte) in quotient.cells.iter().zip(push.cells.iter()) {
+            cb.require_equal(
+                "push == (a < 0 ? !quotient : quotient)",
+                push_byte.expr(),
+                quotient_byte.expr()
+                    + is_neg.clone() * (255.expr() - 2.expr() * quotient_byte.expr()),
+            );
+        }
+
+        let mul_add_words =
+            MulAddWordsGadget::construct(cb, [&quotient, &divisor, &remainder, &dividend]);
+        let divisor_is_zero = IsZeroGadget::construct(cb, sum::expr(&divisor.cells));
+        let remainder_lt_divisor = LtWordGadget::construct(cb, &remainder, &divisor);
+        let shift_hi_is_zero = IsZeroGadget::construct(cb, sum::expr(&shift.cells[1..]));
+
+        cb.require_zero("overflow == 0", mul_add_words.overflow());
+
+        cb.require_equal(
+            "divisor == 0 if and only if shift >= 256",
+            divisor_is_zero.expr(),
+            1.expr() - shift_hi_is_zero.expr(),
+        );
+
+        cb.require_zero(
+            "quotient == 0 when divisor == 0",
+            divisor_is_zero.expr() * sum::expr(&quotient.cells),
+        );
+
+        cb.require_zero(
+            "remainder < divisor when divisor != 0",
+            (1.expr() - divisor_is_zero.expr()) * (1.expr() - remainder_lt_divisor.expr()),
+        );
+
+        // Constrain divisor_lo == 2^shift.cells[0] when shift.cells[0] < 128, and
+        // divisor_hi == 2^(shift.cells[0] - 128) otherwise.
+        let divisor_lo = from_bytes::expr(&divisor.cells[..16]);
+        let divisor_hi = from_bytes::expr(&divisor.cells[16..]);
+        cb.condition(1.expr() - divisor_is_zero.expr(), |cb| {
+            cb.add_lookup(
+                "Pow2 lookup of shift.cells[0], divisor_lo and divisor_hi",
+                Lookup::Fixed {
+                    tag: FixedTableTag::Pow2.expr(),
+                    values: [shift.cells[0].expr(), divisor_lo, divisor_hi],
+                },
+            );
+        });
+
+        cb.charge_gas(OpcodeId::SAR.constant_gas_cost().expr());
+        let step_state_transition = StepStateTransition {
+            rw_counter: Delta(3.expr()),
+            program_counter: Delta(1.expr()),
+            stack_pointer: Delta(1.expr()),
+            ..Default::default()
+        };
+
+        let same_context = SameContextGadget::construct(cb, opcode, step_state_transition);
+
+        Self {
+            same_context,
+            shift,
+            a,
+            push,
+            quotient,
+            divisor,
+            remainder,
+            dividend,
+            sign_check,
+            shift_hi_is_zero,
+            mul_add_words,
+            divisor_is_zero,
+            remainder_lt_divisor,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block<F>,
+        _: &Transaction,
+        _: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        self.same_context.assign_exec_step(region, offset, step)?;
+        let indices = [step.rw_indices[0], step.rw_indices[1], step.rw_indices[2]];
+        let [shift, a, push] = indices.map(|idx| block.rws[idx].stack_value());
+
+        let is_neg = a.msb();
+        let dividend = if is_neg { !a } else { a };
+        let (quotient, divisor) = if shift < U256::from(256) {
+            let divisor = U256::one() << shift.low_u32();
+            (dividend / divisor, divisor)
+        } else {
+            (U256::zero(), U256::zero())
+        };
+        let remainder = dividend - quotient * divisor;
+
+        self.shift
+            .assign(region, offset, Some(shift.to_le_bytes()))?;
+        self.a.assign(region, offset, Some(a.to_le_bytes()))?;
+        self.push.assign(region, offset, Some(push.to_le_bytes()))?;
+        self.quotient
+            .assign(region, offset, Some(quotient.to_le_bytes()))?;
+        self.divisor
+            .assign(region, offset, Some(divisor.to_le_bytes()))?;
+        self.remainder
+            .assign(region, offset, Some(remainder.to_le_bytes()))?;
+        self.dividend
+            .assign(region, offset, Some(dividend.to_le_bytes()))?;
+        self.sign_check.assign(
+            region,
+            offset,
+            F::from(a.to_le_bytes()[31] as u64),
+            F::from(128u64),
+        )?;
+        let shift_hi_sum = (1..32).fold(0, |acc, idx| acc + shift.byte(idx) as u64);
+        self.shift_hi_is_zero
+            .assign(region, offset, F::from(shift_hi_sum))?;
+        self.mul_add_words
+            .assign(region, offset, [quotient, divisor, remainder, dividend])?;
+        let divisor_sum = (0..32).fold(0, |acc, idx| acc + divisor.byte(idx) as u64);
+        self.divisor_is_zero
+            .assign(region, offset, F::from(divisor_sum))?;
+        self.remainder_lt_divisor
+            .assign(region, offset, remainder, divisor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{evm_circuit::test::rand_word, test_util::run_test_circuits};
+    use eth_types::{bytecode, Word};
+    use mock::TestContext;
+
+    fn test_ok(a: Word, shift: Word) {
+        let bytecode = bytecode! {
+            PUSH32(a)
+            PUSH32(shift)
+            SAR
+            STOP
+        };
+
+        assert_eq!(
+            run_test_circuits(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                None
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn sar_gadget_positive() {
+        let a = Word::from(0xABCD) << 232;
+        test_ok(a, Word::from(0));
+        test_ok(a, Word::from(8));
+        test_ok(a, Word::from(129));
+        test_ok(a, Word::from(255));
+        test_ok(a, Word::from(256));
+        test_ok(a, Word::from(256 + 8 + 1));
+    }
+
+    #[test]
+    fn sar_gadget_negative() {
+        let a = Word::from(0xABCD) << 240;
+        test_ok(a, Word::from(0));
+        test_ok(a, Word::from(8));
+        test_ok(a, Word::from(129));
+        // -1 >> 255 == -1 and the sign extends to all bits from 256 on.
+        test_ok(Word::MAX, Word::from(255));
+        test_ok(a, Word::from(255));
+        test_ok(a, Word::from(256));
+        test_ok(a, Word::MAX);
+    }
+
+    #[test]
+    fn sar_gadget_rand() {
+        test_ok(rand_word(), rand_word());
+        test_ok(rand_word(), Word::from(rand_word().byte(0)));
+    }
+}
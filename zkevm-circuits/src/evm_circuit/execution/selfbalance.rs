@@ -94,9 +94,19 @@ impl<F: Field> ExecutionGadget<F> for SelfbalanceGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::test_util::run_test_circuits;
-    use eth_types::bytecode;
-    use mock::TestContext;
+    use crate::{
+        evm_circuit::{
+            test::{pushed_values, run_test_circuit, witness_block_geth_data_default},
+            witness::Rw,
+        },
+        table::AccountFieldTag,
+        test_util::run_test_circuits,
+    };
+    use eth_types::{bytecode, evm_types::OpcodeId, Word};
+    use mock::{
+        eth,
+        test_ctx::{helpers::*, TestContext},
+    };
 
     #[test]
     fn selfbalance_gadget_test() {
@@ -113,4 +123,52 @@ mod test {
             Ok(())
         );
     }
+
+    #[test]
+    fn selfbalance_gadget_pushes_balance() {
+        let bytecode = bytecode! {
+            SELFBALANCE
+            STOP
+        };
+        let value = Word::from(0x3e8);
+        let block = witness_block_geth_data_default(
+            TestContext::<2, 1>::new(
+                None,
+                account_0_code_account_1_no_code(bytecode),
+                |mut txs, accs| {
+                    txs[0]
+                        .from(accs[1].address)
+                        .to(accs[0].address)
+                        .value(value);
+                },
+                |block, _tx| block,
+            )
+            .unwrap()
+            .into(),
+        );
+
+        let (step, pushed) =
+            pushed_values(&block, |step| step.opcode == Some(OpcodeId::SELFBALANCE))[0];
+        let rws = step
+            .rw_indices
+            .iter()
+            .map(|idx| &block.rws[*idx])
+            .collect::<Vec<_>>();
+        // The callee has received the value of the transaction.
+        assert!(matches!(
+            rws[1],
+            Rw::Account {
+                is_write: false,
+                field_tag: AccountFieldTag::Balance,
+                value: balance,
+                ..
+            } if *balance == eth(10) + value
+        ));
+        assert_eq!(pushed, eth(10) + value);
+        assert_eq!(
+            step.gas_cost,
+            OpcodeId::SELFBALANCE.constant_gas_cost().as_u64()
+        );
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
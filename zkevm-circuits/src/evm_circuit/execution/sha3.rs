@@ -153,11 +153,16 @@ impl<F: Field> ExecutionGadget<F> for Sha3Gadget<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_util::run_test_circuits_with_params;
+    use crate::{
+        evm_circuit::test::{pushed_values, run_test_circuit, witness_block_with_bytecode},
+        test_util::run_test_circuits_with_params,
+    };
     use bus_mapping::{
         circuit_input_builder::CircuitsParams,
-        evm::{gen_sha3_code, MemoryKind},
+        evm::{gen_sha3_code, MemoryKind, OpcodeId},
     };
+    use eth_types::{bytecode, word, ToBigEndian, Word};
+    use keccak256::plain::Keccak;
     use mock::TestContext;
 
     fn test_ok(offset: usize, size: usize, mem_kind: MemoryKind) {
@@ -195,4 +200,43 @@ mod tests {
         test_ok(0x303, 0x404, MemoryKind::EqualToSize);
         test_ok(0x404, 0x505, MemoryKind::MoreThanSize);
     }
+
+    #[test]
+    fn sha3_gadget_pushes_digest() {
+        let words = [
+            word!("0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"),
+            word!("0xfffefdfcfbfaf9f8f7f6f5f4f3f2f1f0efeeedecebeae9e8e7e6e5e4e3e2e1e0"),
+        ];
+        let code = bytecode! {
+            PUSH32(words[0])
+            PUSH1(0x00)
+            MSTORE
+            PUSH32(words[1])
+            PUSH1(0x20)
+            MSTORE
+            // Hash the two words.
+            PUSH1(0x40) // size
+            PUSH1(0x00) // offset
+            SHA3
+            // Hash an empty range.
+            PUSH1(0x00) // size
+            PUSH1(0x10) // offset
+            SHA3
+            STOP
+        };
+        let block = witness_block_with_bytecode(code);
+
+        let digest = |input: &[u8]| {
+            let mut keccak = Keccak::default();
+            keccak.update(input);
+            Word::from_big_endian(&keccak.digest())
+        };
+        let memory = [words[0].to_be_bytes(), words[1].to_be_bytes()].concat();
+        let pushed = pushed_values(&block, |step| step.opcode == Some(OpcodeId::SHA3))
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>();
+        assert_eq!(pushed, vec![digest(&memory), digest(&[])]);
+        assert_eq!(run_test_circuit(block), Ok(()));
+    }
 }
@@ -275,6 +275,23 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn signed_comparator_gadget_a_neg_b_pos() {
+        let minus_1 = Word::MAX;
+        let int_min = Word::one() << 255;
+        let int_max = int_min - 1;
+        test_ok(vec![
+            (OpcodeId::SLT, minus_1, Word::zero()),
+            (OpcodeId::SGT, minus_1, Word::zero()),
+            (OpcodeId::SLT, Word::zero(), minus_1),
+            (OpcodeId::SGT, Word::zero(), minus_1),
+            (OpcodeId::SLT, int_min, int_max),
+            (OpcodeId::SGT, int_min, int_max),
+            (OpcodeId::SLT, int_max, int_min),
+            (OpcodeId::SGT, int_max, int_min),
+        ]);
+    }
+
     #[test]
     fn signed_comparator_gadget_a_b_eq_hi_pos() {
         let a = Word::from_big_endian(&[[1u8; 16], [2u8; 16]].concat());
@@ -244,6 +244,18 @@ mod test {
         test_ok(258.into(), 0xF00201.into(), 0xF00201.into());
     }
 
+    #[test]
+    fn signextend_gadget_sign_bounds() {
+        // Extend byte 0 (negative)
+        test_ok(0.into(), 0x0180.into(), Word::MAX - Word::from(0x7F));
+        // Extend byte 15 (positive)
+        test_ok(15.into(), Word::MAX >> 129, Word::from(u128::MAX >> 1));
+        // Extend byte 31 leaves the value unchanged, even when negative
+        test_ok(31.into(), Word::MAX - Word::one(), Word::MAX - Word::one());
+        test_ok(31.into(), Word::one(), Word::one());
+        test_ok(32.into(), 0x80.into(), 0x80.into());
+    }
+
     #[test]
     fn signextend_gadget_rand() {
         let signextend = |index: Word, value: Word| -> Word {
@@ -604,6 +604,26 @@ mod test {
     use eth_types::{bytecode, Word};
     use mock::{test_ctx::helpers::tx_from_1_to_0, TestContext, MOCK_ACCOUNTS};
 
+    #[test]
+    fn sstore_gas_same_value_twice() {
+        // (original, current, new) = (0, v, v): the second write of the same
+        // value only costs a warm access, with no refund.
+        test_ok(0x030201.into(), 0x060504.into(), 0x060504.into(), 0.into());
+    }
+
+    #[test]
+    fn sstore_gas_revert_to_original() {
+        // (original, current, new) = (v, w, v): writing the original value
+        // back costs a warm access and refunds the difference of the first
+        // write.
+        test_ok(
+            0x030201.into(),
+            0x060504.into(),
+            0x060505.into(),
+            0x060504.into(),
+        );
+    }
+
     #[test]
     fn sstore_gadget_no_refund() {
         // value_prev == value
@@ -615,6 +635,18 @@ mod test {
         );
     }
 
+    #[test]
+    fn sstore_gadget_fresh_slot() {
+        // value_prev == original_value == 0, value != 0
+        test_ok(0x030201.into(), 0x060504.into(), 0.into(), 0.into());
+    }
+
+    #[test]
+    fn sstore_gadget_clear_slot() {
+        // value_prev == original_value != 0, value == 0, which is refunded
+        test_ok(0x030201.into(), 0.into(), 0x060504.into(), 0x060504.into());
+    }
+
     #[test]
     fn sstore_gadget_delete_slot() {
         // value_prev != value, original_value != value, value == 0
@@ -127,6 +127,35 @@ mod test {
         test_ok(OpcodeId::SWAP16, Word::from(0x030201), Word::from(0x040506));
     }
 
+    #[test]
+    fn swap_gadget_twice() {
+        // Swapping twice restores the stack, whose pointer is left unchanged
+        // by SWAPn so the following SUB reads the original top two values.
+        let mut bytecode = bytecode! {
+            PUSH32(Word::from(0x030201))
+        };
+        for _ in 0..15 {
+            bytecode.write_op(OpcodeId::DUP1);
+        }
+        bytecode.append(&bytecode! {
+            PUSH32(Word::from(0x040506))
+            SWAP1
+            SWAP1
+            SWAP16
+            SWAP16
+            SUB
+            STOP
+        });
+
+        assert_eq!(
+            run_test_circuits(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                None
+            ),
+            Ok(())
+        );
+    }
+
     #[test]
     #[ignore]
     fn swap_gadget_rand() {
@@ -14,7 +14,7 @@ use halo2_proofs::{
     circuit::Value,
     plonk::{Advice, Column, ConstraintSystem, Error, Expression},
 };
-use std::iter;
+use std::{fmt, iter};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -121,6 +121,13 @@ impl Default for ExecutionState {
     }
 }
 
+impl fmt::Display for ExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The variant names already read as the opcode (group) they handle.
+        write!(f, "{:?}", self)
+    }
+}
+
 impl ExecutionState {
     pub(crate) const fn as_u64(&self) -> u64 {
         *self as u64
@@ -222,83 +229,10 @@ impl ExecutionState {
             Self::MSIZE => vec![OpcodeId::MSIZE],
             Self::GAS => vec![OpcodeId::GAS],
             Self::JUMPDEST => vec![OpcodeId::JUMPDEST],
-            Self::PUSH => vec![
-                OpcodeId::PUSH1,
-                OpcodeId::PUSH2,
-                OpcodeId::PUSH3,
-                OpcodeId::PUSH4,
-                OpcodeId::PUSH5,
-                OpcodeId::PUSH6,
-                OpcodeId::PUSH7,
-                OpcodeId::PUSH8,
-                OpcodeId::PUSH9,
-                OpcodeId::PUSH10,
-                OpcodeId::PUSH11,
-                OpcodeId::PUSH12,
-                OpcodeId::PUSH13,
-                OpcodeId::PUSH14,
-                OpcodeId::PUSH15,
-                OpcodeId::PUSH16,
-                OpcodeId::PUSH17,
-                OpcodeId::PUSH18,
-                OpcodeId::PUSH19,
-                OpcodeId::PUSH20,
-                OpcodeId::PUSH21,
-                OpcodeId::PUSH22,
-                OpcodeId::PUSH23,
-                OpcodeId::PUSH24,
-                OpcodeId::PUSH25,
-                OpcodeId::PUSH26,
-                OpcodeId::PUSH27,
-                OpcodeId::PUSH28,
-                OpcodeId::PUSH29,
-                OpcodeId::PUSH30,
-                OpcodeId::PUSH31,
-                OpcodeId::PUSH32,
-            ],
-            Self::DUP => vec![
-                OpcodeId::DUP1,
-                OpcodeId::DUP2,
-                OpcodeId::DUP3,
-                OpcodeId::DUP4,
-                OpcodeId::DUP5,
-                OpcodeId::DUP6,
-                OpcodeId::DUP7,
-                OpcodeId::DUP8,
-                OpcodeId::DUP9,
-                OpcodeId::DUP10,
-                OpcodeId::DUP11,
-                OpcodeId::DUP12,
-                OpcodeId::DUP13,
-                OpcodeId::DUP14,
-                OpcodeId::DUP15,
-                OpcodeId::DUP16,
-            ],
-            Self::SWAP => vec![
-                OpcodeId::SWAP1,
-                OpcodeId::SWAP2,
-                OpcodeId::SWAP3,
-                OpcodeId::SWAP4,
-                OpcodeId::SWAP5,
-                OpcodeId::SWAP6,
-                OpcodeId::SWAP7,
-                OpcodeId::SWAP8,
-                OpcodeId::SWAP9,
-                OpcodeId::SWAP10,
-                OpcodeId::SWAP11,
-                OpcodeId::SWAP12,
-                OpcodeId::SWAP13,
-                OpcodeId::SWAP14,
-                OpcodeId::SWAP15,
-                OpcodeId::SWAP16,
-            ],
-            Self::LOG => vec![
-                OpcodeId::LOG0,
-                OpcodeId::LOG1,
-                OpcodeId::LOG2,
-                OpcodeId::LOG3,
-                OpcodeId::LOG4,
-            ],
+            Self::PUSH => OpcodeId::PUSH_ALL.to_vec(),
+            Self::DUP => OpcodeId::DUP_ALL.to_vec(),
+            Self::SWAP => OpcodeId::SWAP_ALL.to_vec(),
+            Self::LOG => OpcodeId::LOG_ALL.to_vec(),
             Self::CREATE => vec![OpcodeId::CREATE],
             Self::CALL_OP => vec![
                 OpcodeId::CALL,
@@ -564,3 +498,42 @@ impl<F: FieldExt> Step<F> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ExecutionState;
+    use bus_mapping::evm::OpcodeId;
+    use std::collections::HashMap;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn responsible_opcodes_partition() {
+        let mut states_by_opcode = HashMap::new();
+        for state in ExecutionState::iter() {
+            for opcode in state.responsible_opcodes() {
+                if let Some(other) = states_by_opcode.insert(opcode, state) {
+                    panic!("{:?} is handled by both {} and {}", opcode, other, state);
+                }
+            }
+        }
+        for opcode in OpcodeId::all() {
+            assert!(
+                states_by_opcode.contains_key(&opcode),
+                "{:?} is handled by no execution state",
+                opcode
+            );
+        }
+        assert_eq!(states_by_opcode.len(), OpcodeId::all().count());
+    }
+
+    #[test]
+    fn execution_state_display() {
+        assert_eq!(ExecutionState::STOP.to_string(), "STOP");
+        assert_eq!(ExecutionState::ADD_SUB.to_string(), "ADD_SUB");
+        assert_eq!(ExecutionState::BeginTx.to_string(), "BeginTx");
+        assert_eq!(
+            format!("{}", ExecutionState::ErrorOutOfGasConstant),
+            "ErrorOutOfGasConstant"
+        );
+    }
+}
@@ -4,7 +4,7 @@ pub use crate::table::TxContextFieldTag;
 use bus_mapping::evm::OpcodeId;
 use eth_types::Field;
 use gadgets::util::Expr;
-use halo2_proofs::plonk::Expression;
+use halo2_proofs::plonk::{Column, ConstraintSystem, Expression, Fixed};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -136,6 +136,27 @@ pub(crate) enum Table {
     Exp,
 }
 
+/// The fixed lookup tables shared by all the execution gadgets.  Gadgets
+/// never allocate table columns, they look up a [`Table`] and each table of the
+/// registry is allocated once, so adding gadgets doesn't add fixed columns.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FixedTableRegistry {
+    /// Table of the rows built by [`FixedTableTag::build`]
+    pub(crate) fixed_table: [Column<Fixed>; 4],
+    /// Table of the bytes `0..256`
+    pub(crate) byte_table: [Column<Fixed>; 1],
+}
+
+impl FixedTableRegistry {
+    /// Allocate the columns of the fixed tables.
+    pub(crate) fn configure<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
+        Self {
+            fixed_table: [(); 4].map(|_| meta.fixed_column()),
+            byte_table: [(); 1].map(|_| meta.fixed_column()),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RwValues<F> {
     pub id: Expression<F>,
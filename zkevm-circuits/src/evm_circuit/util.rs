@@ -5,7 +5,7 @@ use crate::{
     },
     util::{query_expression, Expr},
 };
-use eth_types::U256;
+use eth_types::{WordLimbs, U256};
 use halo2_proofs::{
     arithmetic::FieldExt,
     circuit::{AssignedCell, Region, Value},
@@ -74,6 +74,10 @@ impl<F: FieldExt> Expr<F> for &Cell<F> {
 pub struct CachedRegion<'r, 'b, F: FieldExt> {
     region: &'r mut Region<'b, F>,
     advice: Vec<Vec<F>>,
+    /// Whether each cached advice cell has already been assigned, to catch
+    /// overwrites with a different value.
+    #[cfg(debug_assertions)]
+    assigned: Vec<Vec<bool>>,
     power_of_randomness: [F; 31],
     width_start: usize,
     height_start: usize,
@@ -92,6 +96,8 @@ impl<'r, 'b, F: FieldExt> CachedRegion<'r, 'b, F> {
         Self {
             region,
             advice: vec![vec![F::zero(); height]; width],
+            #[cfg(debug_assertions)]
+            assigned: vec![vec![false; height]; width],
             power_of_randomness,
             width_start,
             height_start,
@@ -117,13 +123,33 @@ impl<'r, 'b, F: FieldExt> CachedRegion<'r, 'b, F> {
         // Cache the value
         if let Result::Ok(cell) = &res {
             cell.value_field().map(|f| {
-                self.advice[column.index() - self.width_start][offset - self.height_start] =
-                    f.evaluate();
+                self.cache_advice(
+                    column.index() - self.width_start,
+                    offset - self.height_start,
+                    f.evaluate(),
+                );
             });
         }
         res
     }
 
+    fn cache_advice(&mut self, column_index: usize, row_index: usize, value: F) {
+        #[cfg(debug_assertions)]
+        {
+            let assigned = &mut self.assigned[column_index][row_index];
+            assert!(
+                !*assigned || self.advice[column_index][row_index] == value,
+                "advice cell at column {} offset {} overwritten: {:?} -> {:?}",
+                column_index + self.width_start,
+                row_index + self.height_start,
+                self.advice[column_index][row_index],
+                value
+            );
+            *assigned = true;
+        }
+        self.advice[column_index][row_index] = value;
+    }
+
     pub fn get_fixed(&self, _row_index: usize, _column_index: usize, _rotation: Rotation) -> F {
         unimplemented!("fixed column");
     }
@@ -473,18 +499,97 @@ pub(crate) fn pow_of_two_expr<F: FieldExt>(by: usize) -> Expression<F> {
 
 /// Returns tuple consists of low and high part of U256
 pub(crate) fn split_u256(value: &U256) -> (U256, U256) {
+    let [limb0, limb1, limb2, limb3] = value.to_u64_limbs();
     (
-        U256([value.0[0], value.0[1], 0, 0]),
-        U256([value.0[2], value.0[3], 0, 0]),
+        U256::from_u64_limbs([limb0, limb1, 0, 0]),
+        U256::from_u64_limbs([limb2, limb3, 0, 0]),
     )
 }
 
 /// Split a U256 value into 4 64-bit limbs stored in U256 values.
 pub(crate) fn split_u256_limb64(value: &U256) -> [U256; 4] {
-    [
-        U256([value.0[0], 0, 0, 0]),
-        U256([value.0[1], 0, 0, 0]),
-        U256([value.0[2], 0, 0, 0]),
-        U256([value.0[3], 0, 0, 0]),
-    ]
+    value.to_u64_limbs().map(U256::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_bytes, split_u256_limb64, CachedRegion};
+    use crate::evm_circuit::test::rand_word;
+    use eth_types::{ToLittleEndian, ToScalar};
+    use halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        dev::MockProver,
+        halo2curves::bn256::Fr,
+        plonk::{Advice, Circuit, Column, ConstraintSystem, Error},
+    };
+
+    /// Assigns `values` one after the other to the same cell.
+    #[derive(Default)]
+    struct OverwriteCircuit {
+        values: Vec<u64>,
+    }
+
+    impl Circuit<Fr> for OverwriteCircuit {
+        type Config = Column<Advice>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            meta.advice_column()
+        }
+
+        fn synthesize(
+            &self,
+            column: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            layouter.assign_region(
+                || "overwrite",
+                |mut region| {
+                    let mut region =
+                        CachedRegion::new(&mut region, [Fr::zero(); 31], 1, 1, column.index(), 0);
+                    for value in self.values.iter() {
+                        region.assign_advice(
+                            || "cell",
+                            column,
+                            0,
+                            || Value::known(Fr::from(*value)),
+                        )?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn cached_region_same_value() {
+        let circuit = OverwriteCircuit { values: vec![1, 1] };
+        MockProver::<Fr>::run(4, &circuit, vec![]).unwrap();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overwritten")]
+    fn cached_region_overwrite() {
+        let circuit = OverwriteCircuit { values: vec![1, 2] };
+        let _ = MockProver::<Fr>::run(4, &circuit, vec![]);
+    }
+
+    #[test]
+    fn split_u256_limb64_matches_limb_exprs() {
+        // The arithmetic gadgets build the limb i of a word from its cells
+        // 8 * i..8 * (i + 1) with from_bytes::expr.
+        let word = rand_word();
+        let bytes = word.to_le_bytes();
+        for (idx, limb) in split_u256_limb64(&word).iter().enumerate() {
+            assert_eq!(
+                limb.to_scalar(),
+                Some(from_bytes::value::<Fr>(&bytes[8 * idx..8 * (idx + 1)]))
+            );
+        }
+    }
 }
@@ -275,6 +275,7 @@ pub(crate) struct ConstraintBuilder<'a, F> {
     condition: Option<Expression<F>>,
     constraints_location: ConstraintLocation,
     stored_expressions: Vec<StoredExpression<F>>,
+    gas_cost: Option<Expression<F>>,
 }
 
 impl<'a, F: Field> ConstraintBuilder<'a, F> {
@@ -304,6 +305,7 @@ impl<'a, F: Field> ConstraintBuilder<'a, F> {
             condition: None,
             constraints_location: ConstraintLocation::Step,
             stored_expressions: Vec::new(),
+            gas_cost: None,
         }
     }
 
@@ -454,10 +456,39 @@ impl<'a, F: Field> ConstraintBuilder<'a, F> {
         self.add_constraint("Constrain next execution state not", next_state.expr());
     }
 
+    /// Charge `cost` to the gas of the step, under the current condition if
+    /// any.  The accumulated cost becomes the `gas_left` delta in
+    /// [`Self::require_step_state_transition`], and the `SameContextGadget`
+    /// range check on the next `gas_left` rules out the out of gas case.
+    pub(crate) fn charge_gas(&mut self, cost: Expression<F>) {
+        let cost = match &self.condition {
+            Some(condition) => condition.clone() * cost,
+            None => cost,
+        };
+        self.gas_cost = Some(match self.gas_cost.take() {
+            Some(gas_cost) => gas_cost + cost,
+            None => cost,
+        });
+    }
+
+    /// Sum of the gas charged so far with [`Self::charge_gas`].
+    pub(crate) fn gas_cost(&self) -> Expression<F> {
+        self.gas_cost.clone().unwrap_or_else(|| 0.expr())
+    }
+
     pub(crate) fn require_step_state_transition(
         &mut self,
-        step_state_transition: StepStateTransition<F>,
+        mut step_state_transition: StepStateTransition<F>,
     ) {
+        if let Some(gas_cost) = self.gas_cost.clone() {
+            assert!(
+                matches!(step_state_transition.gas_left, Transition::Same),
+                "gas_left transition of {:?} is set while gas is charged",
+                self.execution_state
+            );
+            step_state_transition.gas_left = Transition::Delta(-gas_cost);
+        }
+
         macro_rules! constrain {
             ($name:tt) => {
                 match step_state_transition.$name {
@@ -1347,6 +1378,32 @@ impl<'a, F: Field> ConstraintBuilder<'a, F> {
         ret
     }
 
+    /// Constrain the next step to be in `next_step_state`, continuing the same
+    /// multi-step operation.  The `constraint` function queries the next step
+    /// cells (see [`Self::constrain_next_step`]) and returns the expressions
+    /// that must be carried over, in the same order as `carried`, which holds
+    /// the values of the current step.
+    pub(crate) fn constrain_next_step_carry_over<R>(
+        &mut self,
+        next_step_state: ExecutionState,
+        condition: Option<Expression<F>>,
+        carried: Vec<(&'static str, Expression<F>)>,
+        constraint: impl FnOnce(&mut Self) -> (Vec<Expression<F>>, R),
+    ) -> R {
+        self.constrain_next_step(next_step_state, condition, |cb| {
+            let (next, ret) = constraint(cb);
+            assert_eq!(
+                carried.len(),
+                next.len(),
+                "Carried over values must match the next step ones"
+            );
+            for ((name, curr), next) in carried.into_iter().zip(next) {
+                cb.require_equal(name, next, curr);
+            }
+            ret
+        })
+    }
+
     pub(crate) fn add_constraints(&mut self, constraints: Vec<(&'static str, Expression<F>)>) {
         for (name, constraint) in constraints {
             self.add_constraint(name, constraint);
@@ -1524,3 +1581,130 @@ impl<'a, F: Field> ConstraintBuilder<'a, F> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm_circuit::{
+        step::DynamicSelectorHalf,
+        util::{
+            math_gadget::test_util::{test_math_gadget_container, MathGadgetContainer},
+            CachedRegion, Cell,
+        },
+    };
+    use eth_types::{ToScalar, Word};
+    use halo2_proofs::{circuit::Value, halo2curves::bn256::Fr, plonk::Error};
+
+    #[derive(Clone)]
+    /// CarryOverTestContainer: require(next.b == curr.a)
+    struct CarryOverTestContainer<F> {
+        a: Cell<F>,
+        b: Cell<F>,
+        next_execution_state: DynamicSelectorHalf<F>,
+    }
+
+    impl<F: Field> MathGadgetContainer<F> for CarryOverTestContainer<F> {
+        fn configure_gadget_container(cb: &mut ConstraintBuilder<F>) -> Self {
+            let a = cb.query_cell();
+            let b = cb.constrain_next_step_carry_over(
+                ExecutionState::STOP,
+                None,
+                vec![("a carried over to the next step", a.expr())],
+                |cb| {
+                    let b = cb.query_cell();
+                    (vec![b.expr()], b)
+                },
+            );
+            CarryOverTestContainer {
+                a,
+                b,
+                next_execution_state: cb.next.state.execution_state.clone(),
+            }
+        }
+
+        fn assign_gadget_container(
+            &self,
+            witnesses: &[Word],
+            region: &mut CachedRegion<'_, '_, F>,
+        ) -> Result<(), Error> {
+            let offset = 0;
+            self.next_execution_state
+                .assign(region, offset, ExecutionState::STOP as usize)?;
+            self.a.assign(
+                region,
+                offset,
+                Value::known(witnesses[0].to_scalar().unwrap()),
+            )?;
+            self.b.assign(
+                region,
+                offset,
+                Value::known(witnesses[1].to_scalar().unwrap()),
+            )?;
+            Ok(())
+        }
+    }
+
+    #[derive(Clone)]
+    /// ChargeGasTestContainer: require(total == a + b)
+    struct ChargeGasTestContainer<F> {
+        a: Cell<F>,
+        b: Cell<F>,
+        total: Cell<F>,
+    }
+
+    impl<F: Field> MathGadgetContainer<F> for ChargeGasTestContainer<F> {
+        fn configure_gadget_container(cb: &mut ConstraintBuilder<F>) -> Self {
+            let a = cb.query_cell();
+            let b = cb.query_cell();
+            let total = cb.query_cell();
+            cb.charge_gas(a.expr());
+            cb.condition(1.expr(), |cb| cb.charge_gas(b.expr()));
+            cb.require_equal("total is the charged gas", total.expr(), cb.gas_cost());
+            ChargeGasTestContainer { a, b, total }
+        }
+
+        fn assign_gadget_container(
+            &self,
+            witnesses: &[Word],
+            region: &mut CachedRegion<'_, '_, F>,
+        ) -> Result<(), Error> {
+            let offset = 0;
+            for (cell, witness) in [&self.a, &self.b, &self.total].iter().zip(witnesses) {
+                cell.assign(region, offset, Value::known(witness.to_scalar().unwrap()))?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_charge_gas_sum() {
+        test_math_gadget_container::<Fr, ChargeGasTestContainer<Fr>>(
+            vec![Word::from(3), Word::from(21000), Word::from(21003)],
+            true,
+        );
+    }
+
+    #[test]
+    fn test_charge_gas_mismatch() {
+        test_math_gadget_container::<Fr, ChargeGasTestContainer<Fr>>(
+            vec![Word::from(3), Word::from(21000), Word::from(21000)],
+            false,
+        );
+    }
+
+    #[test]
+    fn test_carry_over_same() {
+        test_math_gadget_container::<Fr, CarryOverTestContainer<Fr>>(
+            vec![Word::from(7), Word::from(7)],
+            true,
+        );
+    }
+
+    #[test]
+    fn test_carry_over_mismatch() {
+        test_math_gadget_container::<Fr, CarryOverTestContainer<Fr>>(
+            vec![Word::from(7), Word::from(8)],
+            false,
+        );
+    }
+}
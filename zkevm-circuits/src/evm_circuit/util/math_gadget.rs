@@ -21,7 +21,7 @@ mod mul_word_u64;
 mod pair_select;
 mod range_check;
 #[cfg(test)]
-mod test_util;
+pub(crate) mod test_util;
 
 pub(crate) use abs_word::AbsWordGadget;
 pub(crate) use add_words::AddWordsGadget;
@@ -1,6 +1,6 @@
 use super::util::{
     extract_field, get_num_bits_per_lookup, load_lookup_table, load_normalize_table,
-    load_pack_table, CHI_BASE_LOOKUP_TABLE, NUM_ROUNDS,
+    load_pack_table, LoadProgress, CHI_BASE_LOOKUP_TABLE, NUM_ROUNDS,
 };
 use crate::evm_circuit::util::{not, rlc};
 use crate::keccak_circuit::util::{
@@ -1714,17 +1714,32 @@ impl<F: Field> KeccakCircuitConfig<F> {
     }
 
     pub(crate) fn load_aux_tables(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
-        load_normalize_table(layouter, "normalize_6", &self.normalize_6, 6u64)?;
-        load_normalize_table(layouter, "normalize_4", &self.normalize_4, 4u64)?;
-        load_normalize_table(layouter, "normalize_3", &self.normalize_3, 3u64)?;
+        self.load_aux_tables_with_progress(layouter, None)
+    }
+
+    /// Loads the lookup tables, reporting the progress of their assignment to
+    /// `progress`.
+    pub fn load_aux_tables_with_progress(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        mut progress: Option<&mut LoadProgress>,
+    ) -> Result<(), Error> {
+        for (name, table, range) in [
+            ("normalize_6", &self.normalize_6, 6u64),
+            ("normalize_4", &self.normalize_4, 4u64),
+            ("normalize_3", &self.normalize_3, 3u64),
+        ] {
+            load_normalize_table(layouter, name, table, range, progress.as_deref_mut())?;
+        }
         load_lookup_table(
             layouter,
             "chi base",
             &self.chi_base_table,
             get_num_bits_per_base_chi_lookup(),
             &CHI_BASE_LOOKUP_TABLE,
+            progress.as_deref_mut(),
         )?;
-        load_pack_table(layouter, &self.pack_table)
+        load_pack_table(layouter, &self.pack_table, progress)
     }
 }
 
@@ -2131,6 +2146,7 @@ mod tests {
     use super::*;
     use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr};
     use log::error;
+    use std::cell::RefCell;
 
     fn verify<F: Field>(k: u32, inputs: Vec<Vec<u8>>, success: bool) {
         let circuit = KeccakCircuit::new(Some(2usize.pow(k)), inputs);
@@ -2159,4 +2175,73 @@ mod tests {
         ];
         verify::<Fr>(k, inputs, true);
     }
+
+    /// Circuit only loading the lookup tables, recording the reported progress.
+    #[derive(Default)]
+    struct LoadProgressCircuit {
+        interval: usize,
+        reports: RefCell<Vec<(String, usize)>>,
+    }
+
+    impl Circuit<Fr> for LoadProgressCircuit {
+        type Config = KeccakCircuitConfig<Fr>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let keccak_table = KeccakTable::construct(meta);
+            let challenges = Challenges::construct(meta).exprs(meta);
+            KeccakCircuitConfig::new(
+                meta,
+                KeccakCircuitConfigArgs {
+                    keccak_table,
+                    challenges,
+                },
+            )
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let mut callback = |name: &str, num_rows: usize| {
+                self.reports.borrow_mut().push((name.to_string(), num_rows))
+            };
+            let mut progress = LoadProgress::new(self.interval, &mut callback);
+            config.load_aux_tables_with_progress(&mut layouter, Some(&mut progress))
+        }
+    }
+
+    #[test]
+    fn load_aux_tables_progress() {
+        let circuit = LoadProgressCircuit {
+            interval: 16,
+            ..Default::default()
+        };
+        MockProver::<Fr>::run(11, &circuit, vec![]).unwrap();
+
+        let num_normalize_rows = |range: usize| range.pow(get_num_bits_per_lookup(range) as u32);
+        let num_chi_base_rows = CHI_BASE_LOOKUP_TABLE
+            .len()
+            .pow(get_num_bits_per_base_chi_lookup() as u32);
+        let tables = [
+            ("normalize_6", num_normalize_rows(6)),
+            ("normalize_4", num_normalize_rows(4)),
+            ("normalize_3", num_normalize_rows(3)),
+            ("chi base", num_chi_base_rows),
+            ("pack", 256),
+        ];
+        let expected: Vec<_> = tables
+            .iter()
+            .flat_map(|(name, num_rows)| {
+                (1..=num_rows / circuit.interval)
+                    .map(|idx| (name.to_string(), idx * circuit.interval))
+            })
+            .collect();
+        assert_eq!(circuit.reports.into_inner(), expected);
+    }
 }
@@ -347,12 +347,35 @@ pub fn get_num_bits_per_lookup(range: usize) -> usize {
     num_bits as usize
 }
 
+/// Progress of the assignment of the lookup tables, reported with the name of
+/// the table and the number of rows assigned so far every `interval` rows.
+pub struct LoadProgress<'a> {
+    interval: usize,
+    callback: &'a mut dyn FnMut(&str, usize),
+}
+
+impl<'a> LoadProgress<'a> {
+    /// Create a new LoadProgress invoking `callback` every `interval` rows.
+    pub fn new(interval: usize, callback: &'a mut dyn FnMut(&str, usize)) -> Self {
+        assert!(interval > 0, "progress interval must be positive");
+        Self { interval, callback }
+    }
+
+    fn report(&mut self, name: &str, offset: usize) {
+        let num_rows = offset + 1;
+        if num_rows % self.interval == 0 {
+            (self.callback)(name, num_rows);
+        }
+    }
+}
+
 /// Loads a normalization table with the given parameters
 pub fn load_normalize_table<F: Field>(
     layouter: &mut impl Layouter<F>,
     name: &str,
     tables: &[TableColumn; 2],
     range: u64,
+    mut progress: Option<&mut LoadProgress>,
 ) -> Result<(), Error> {
     let part_size = get_num_bits_per_lookup(range as usize);
     layouter.assign_table(
@@ -383,6 +406,9 @@ pub fn load_normalize_table<F: Field>(
                     offset,
                     || Value::known(F::from(output)),
                 )?;
+                if let Some(progress) = progress.as_mut() {
+                    progress.report(name, offset);
+                }
             }
             Ok(())
         },
@@ -393,6 +419,7 @@ pub fn load_normalize_table<F: Field>(
 pub fn load_pack_table<F: Field>(
     layouter: &mut impl Layouter<F>,
     tables: &[TableColumn; 2],
+    mut progress: Option<&mut LoadProgress>,
 ) -> Result<(), Error> {
     layouter.assign_table(
         || "pack table",
@@ -406,6 +433,9 @@ pub fn load_pack_table<F: Field>(
                 )?;
                 let packed: F = pack(&into_bits(&[idx as u8]));
                 table.assign_cell(|| "packed", tables[1], offset, || Value::known(packed))?;
+                if let Some(progress) = progress.as_mut() {
+                    progress.report("pack", offset);
+                }
             }
             Ok(())
         },
@@ -419,6 +449,7 @@ pub fn load_lookup_table<F: Field>(
     tables: &[TableColumn; 2],
     part_size: usize,
     lookup_table: &[u8],
+    mut progress: Option<&mut LoadProgress>,
 ) -> Result<(), Error> {
     layouter.assign_table(
         || format!("{} table", name),
@@ -448,6 +479,9 @@ pub fn load_lookup_table<F: Field>(
                     offset,
                     || Value::known(F::from(output)),
                 )?;
+                if let Some(progress) = progress.as_mut() {
+                    progress.report(name, offset);
+                }
             }
             Ok(())
         },
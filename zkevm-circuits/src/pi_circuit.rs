@@ -57,6 +57,7 @@ pub struct TxValues {
     call_data_len: u64,
     call_data_gas_cost: u64,
     tx_sign_hash: [u8; 32],
+    sig_v: u64,
 }
 
 /// Extra values (not contained in block or tx tables)
@@ -127,7 +128,7 @@ impl PublicData {
                 gas: tx.gas_limit,
                 from_addr: tx.from,
                 to_addr: tx.to.unwrap_or_else(Address::zero),
-                is_create: (tx.to.is_none() as u64),
+                is_create: tx.is_create() as u64,
                 value: tx.value,
                 call_data_len: tx.call_data.0.len() as u64,
                 call_data_gas_cost: tx.call_data.0.iter().fold(0, |acc, byte| {
@@ -138,6 +139,7 @@ impl PublicData {
                     }
                 }),
                 tx_sign_hash: msg_hash_le,
+                sig_v: tx.v,
             });
         }
         tx_vals
@@ -1289,6 +1291,7 @@ impl<F: Field> SubCircuit<F> for PiCircuit<F> {
                             TxFieldTag::TxSignHash,
                             rlc(tx.tx_sign_hash, self.randomness),
                         ),
+                        (TxFieldTag::SigV, F::from(tx.sig_v)),
                     ] {
                         config.assign_tx_row(
                             &mut region,
@@ -1525,6 +1528,7 @@ fn raw_public_inputs_col<F: Field>(
             F::from(tx.call_data_len),
             F::from(tx.call_data_gas_cost),
             rlc(tx.tx_sign_hash, randomness),
+            F::from(tx.sig_v),
         ] {
             result[id_offset + offset] = F::from((i + 1) as u64);
             result[index_offset + offset] = F::zero();
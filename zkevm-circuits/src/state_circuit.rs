@@ -9,12 +9,12 @@ mod test;
 
 use crate::{
     evm_circuit::param::N_BYTES_WORD,
-    table::{LookupTable, MptTable, RwTable, RwTableTag},
+    table::{AccountFieldTag, BytecodeTable, LookupTable, MptTable, RwTable, RwTableTag},
     util::{Challenges, Expr, SubCircuit, SubCircuitConfig},
-    witness::{self, MptUpdates, Rw, RwMap},
+    witness::{self, Bytecode, MptUpdates, Rw, RwMap},
 };
 use constraint_builder::{ConstraintBuilder, Queries};
-use eth_types::{Address, Field};
+use eth_types::{Address, Field, U256};
 use gadgets::binary_number::{BinaryNumberChip, BinaryNumberConfig};
 use halo2_proofs::{
     circuit::{Layouter, Region, SimpleFloorPlanner, Value},
@@ -33,7 +33,7 @@ use std::collections::HashMap;
 use std::{iter::once, marker::PhantomData};
 
 use self::{
-    constraint_builder::{MptUpdateTableQueries, RwTableQueries},
+    constraint_builder::{BytecodeTableQueries, MptUpdateTableQueries, RwTableQueries},
     lexicographic_ordering::LimbIndex,
 };
 
@@ -41,6 +41,31 @@ const N_LIMBS_RW_COUNTER: usize = 2;
 const N_LIMBS_ACCOUNT_ADDRESS: usize = 10;
 const N_LIMBS_ID: usize = 2;
 
+/// How the equality of the word values of two consecutive rows in the same
+/// access group is checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueEquality {
+    /// Compare the RLC encoded values.  This is sound only if the randomness
+    /// is a proper challenge.
+    Rlc,
+    /// Decompose the word values of Stack and AccountStorage rows into bytes
+    /// and compare them one by one.  Meant for debugging the equality logic
+    /// without relying on the RLC.
+    ///
+    /// The rw table only holds the RLC of a word, so the bytes are still
+    /// bound to it by one RLC gate per row; the equality of two rows is then
+    /// checked on the bytes alone.  This costs 32 range checked advice
+    /// columns, which is acceptable because the mode is never the default and
+    /// is not used by the super circuit.
+    ByteWise,
+}
+
+impl Default for ValueEquality {
+    fn default() -> Self {
+        Self::Rlc
+    }
+}
+
 /// Config for StateCircuit
 #[derive(Clone)]
 pub struct StateCircuitConfig<F> {
@@ -59,13 +84,20 @@ pub struct StateCircuitConfig<F> {
     // TODO: use BatchedIsZeroGadget here, once it doesn't depend on the evm circuit constraint
     // builder.
     is_non_exist: Column<Advice>,
+    // For Rw::Account, 1 if the field is the code hash, whose value is looked up
+    // in the bytecode table, and 0 otherwise.
+    is_code_hash: Column<Advice>,
     state_root: Column<Advice>,
     lexicographic_ordering: LexicographicOrderingConfig,
     not_first_access: Column<Advice>,
+    // Little endian bytes of the word value, only with
+    // `ValueEquality::ByteWise`.
+    value_bytes: Option<RlcConfig<N_BYTES_WORD>>,
     lookups: LookupsConfig,
     power_of_randomness: [Expression<F>; N_BYTES_WORD - 1],
     // External tables
     mpt_table: MptTable,
+    bytecode_table: BytecodeTable,
 }
 
 /// Circuit configuration arguments
@@ -74,8 +106,12 @@ pub struct StateCircuitConfigArgs<F: Field> {
     pub rw_table: RwTable,
     /// MptTable
     pub mpt_table: MptTable,
+    /// BytecodeTable
+    pub bytecode_table: BytecodeTable,
     /// Challenges
     pub challenges: Challenges<Expression<F>>,
+    /// Value equality check mode
+    pub value_equality: ValueEquality,
 }
 
 impl<F: Field> SubCircuitConfig<F> for StateCircuitConfig<F> {
@@ -87,7 +123,9 @@ impl<F: Field> SubCircuitConfig<F> for StateCircuitConfig<F> {
         Self::ConfigArgs {
             rw_table,
             mpt_table,
+            bytecode_table,
             challenges,
+            value_equality,
         }: Self::ConfigArgs,
     ) -> Self {
         let selector = meta.fixed_column();
@@ -106,6 +144,11 @@ impl<F: Field> SubCircuitConfig<F> for StateCircuitConfig<F> {
             challenges.evm_word_powers_of_randomness(),
         );
 
+        let value_bytes = match value_equality {
+            ValueEquality::Rlc => None,
+            ValueEquality::ByteWise => Some(RlcChip::configure_bytes(meta, lookups)),
+        };
+
         let initial_value = meta.advice_column_in(SecondPhase);
         let is_non_exist = meta.advice_column_in(SecondPhase);
         let state_root = meta.advice_column_in(SecondPhase);
@@ -131,13 +174,16 @@ impl<F: Field> SubCircuitConfig<F> for StateCircuitConfig<F> {
             sort_keys,
             initial_value,
             is_non_exist,
+            is_code_hash: meta.advice_column(),
             state_root,
             lexicographic_ordering,
             not_first_access: meta.advice_column(),
+            value_bytes,
             lookups,
             power_of_randomness: challenges.evm_word_powers_of_randomness(),
             rw_table,
             mpt_table,
+            bytecode_table,
         };
 
         let mut constraint_builder = ConstraintBuilder::new();
@@ -226,6 +272,14 @@ impl<F: Field> StateCircuitConfig<F> {
                     .assign(region, offset, randomness, storage_key)?;
             }
 
+            if let Some(value_bytes) = &self.value_bytes {
+                let value = match row {
+                    Rw::Stack { value, .. } | Rw::AccountStorage { value, .. } => *value,
+                    _ => U256::zero(),
+                };
+                value_bytes.assign(region, offset, randomness, value)?;
+            }
+
             if let Some(prev_row) = prev_row {
                 let index = self
                     .lexicographic_ordering
@@ -294,6 +348,20 @@ impl<F: Field> StateCircuitConfig<F> {
                 || is_non_exist,
             )?;
 
+            let is_code_hash = matches!(
+                row,
+                Rw::Account {
+                    field_tag: AccountFieldTag::CodeHash,
+                    ..
+                }
+            );
+            region.assign_advice(
+                || "is_code_hash",
+                self.is_code_hash,
+                offset,
+                || Value::known(F::from(is_code_hash as u64)),
+            )?;
+
             // TODO: Switch from Rw::Start -> Rw::Padding to simplify this logic.
             // State root assignment is at previous row (offset - 1) because the state root
             // changes on the last access row.
@@ -348,6 +416,9 @@ pub struct StateCircuit<F> {
     /// Rw rows
     pub rows: Vec<Rw>,
     updates: MptUpdates,
+    /// Bytecodes whose hashes the account code hashes are looked up in, only
+    /// loaded by the standalone circuit
+    bytecodes: Vec<Bytecode>,
     pub(crate) n_rows: usize,
     #[cfg(test)]
     overrides: HashMap<(test::AdviceColumn, isize), F>,
@@ -362,12 +433,21 @@ impl<F: Field> StateCircuit<F> {
         Self {
             rows,
             updates,
+            bytecodes: Vec::new(),
             n_rows,
             #[cfg(test)]
             overrides: HashMap::new(),
             _marker: PhantomData::default(),
         }
     }
+
+    /// Set the bytecodes loaded into the bytecode table by the standalone
+    /// circuit, which must contain the code of every account whose code hash is
+    /// accessed.
+    pub fn with_bytecodes(mut self, bytecodes: impl IntoIterator<Item = Bytecode>) -> Self {
+        self.bytecodes = bytecodes.into_iter().collect();
+        self
+    }
 }
 
 #[cfg(any(feature = "test", test))]
@@ -376,6 +456,7 @@ impl<F: Field> SubCircuit<F> for StateCircuit<F> {
 
     fn new_from_block(block: &witness::Block<F>) -> Self {
         Self::new(block.rws.clone(), block.circuits_params.max_rws)
+            .with_bytecodes(block.bytecodes.values().cloned())
     }
 
     /// Return the minimum number of rows required to prove the block
@@ -443,20 +524,15 @@ impl<F: Field> SubCircuit<F> for StateCircuit<F> {
 }
 
 #[cfg(any(feature = "test", test))]
-impl<F: Field> Circuit<F> for StateCircuit<F>
-where
-    F: Field,
-{
-    type Config = (StateCircuitConfig<F>, Challenges);
-    type FloorPlanner = SimpleFloorPlanner;
-
-    fn without_witnesses(&self) -> Self {
-        Self::default()
-    }
-
-    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+impl<F: Field> StateCircuit<F> {
+    /// Configure the circuit with the given value equality check mode.
+    pub fn configure_with_value_equality(
+        meta: &mut ConstraintSystem<F>,
+        value_equality: ValueEquality,
+    ) -> (StateCircuitConfig<F>, Challenges) {
         let rw_table = RwTable::construct(meta);
         let mpt_table = MptTable::construct(meta);
+        let bytecode_table = BytecodeTable::construct(meta);
         let challenges = Challenges::construct(meta);
 
         let config = {
@@ -466,13 +542,32 @@ where
                 StateCircuitConfigArgs {
                     rw_table,
                     mpt_table,
+                    bytecode_table,
                     challenges,
+                    value_equality,
                 },
             )
         };
 
         (config, challenges)
     }
+}
+
+#[cfg(any(feature = "test", test))]
+impl<F: Field> Circuit<F> for StateCircuit<F>
+where
+    F: Field,
+{
+    type Config = (StateCircuitConfig<F>, Challenges);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        Self::configure_with_value_equality(meta, ValueEquality::default())
+    }
 
     fn synthesize(
         &self,
@@ -483,6 +578,9 @@ where
         config
             .mpt_table
             .load(&mut layouter, &self.updates, challenges.evm_word())?;
+        config
+            .bytecode_table
+            .load(&mut layouter, self.bytecodes.iter(), &challenges)?;
         self.synthesize_sub(&config, &challenges, &mut layouter)
     }
 }
@@ -492,6 +590,7 @@ fn queries<F: Field>(meta: &mut VirtualCells<'_, F>, c: &StateCircuitConfig<F>)
     let final_bits_sum = meta.query_advice(first_different_limb.bits[3], Rotation::cur())
         + meta.query_advice(first_different_limb.bits[4], Rotation::cur());
     let mpt_update_table_expressions = c.mpt_table.table_exprs(meta);
+    let bytecode_table_expressions = c.bytecode_table.table_exprs(meta);
 
     Queries {
         selector: meta.query_fixed(c.selector, Rotation::cur()),
@@ -522,6 +621,10 @@ fn queries<F: Field>(meta: &mut VirtualCells<'_, F>, c: &StateCircuitConfig<F>)
             new_value: mpt_update_table_expressions[5].clone(),
             old_value: mpt_update_table_expressions[6].clone(),
         },
+        bytecode_table: BytecodeTableQueries {
+            code_hash: bytecode_table_expressions[0].clone(),
+            tag: bytecode_table_expressions[1].clone(),
+        },
         lexicographic_ordering_selector: meta
             .query_fixed(c.lexicographic_ordering.selector, Rotation::cur()),
         rw_counter: MpiQueries::new(meta, c.sort_keys.rw_counter),
@@ -545,10 +648,17 @@ fn queries<F: Field>(meta: &mut VirtualCells<'_, F>, c: &StateCircuitConfig<F>)
         initial_value: meta.query_advice(c.initial_value, Rotation::cur()),
         initial_value_prev: meta.query_advice(c.initial_value, Rotation::prev()),
         is_non_exist: meta.query_advice(c.is_non_exist, Rotation::cur()),
+        is_code_hash: meta.query_advice(c.is_code_hash, Rotation::cur()),
         lookups: LookupsQueries::new(meta, c.lookups),
         power_of_randomness: c.power_of_randomness.clone(),
         first_different_limb: [0, 1, 2, 3]
             .map(|idx| meta.query_advice(first_different_limb.bits[idx], Rotation::cur())),
+        value_bytes: c.value_bytes.map(|value_bytes| {
+            (
+                RlcQueries::new(meta, value_bytes),
+                RlcQueries::new_at(meta, value_bytes, Rotation::prev()),
+            )
+        }),
         not_first_access: meta.query_advice(c.not_first_access, Rotation::cur()),
         last_access: 1.expr() - meta.query_advice(c.not_first_access, Rotation::next()),
         state_root: meta.query_advice(c.state_root, Rotation::cur()),
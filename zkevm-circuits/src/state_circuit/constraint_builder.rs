@@ -6,7 +6,7 @@ use super::{
 use crate::util::Expr;
 use crate::{
     evm_circuit::{param::N_BYTES_WORD, util::not},
-    table::{AccountFieldTag, ProofType, RwTableTag},
+    table::{AccountFieldTag, BytecodeFieldTag, ProofType, RwTableTag},
 };
 use eth_types::Field;
 use gadgets::binary_number::BinaryNumberConfig;
@@ -41,11 +41,18 @@ pub struct MptUpdateTableQueries<F: Field> {
     pub old_value: Expression<F>,
 }
 
+#[derive(Clone)]
+pub struct BytecodeTableQueries<F: Field> {
+    pub code_hash: Expression<F>,
+    pub tag: Expression<F>,
+}
+
 #[derive(Clone)]
 pub struct Queries<F: Field> {
     pub selector: Expression<F>,
     pub rw_table: RwTableQueries<F>,
     pub mpt_update_table: MptUpdateTableQueries<F>,
+    pub bytecode_table: BytecodeTableQueries<F>,
     pub lexicographic_ordering_selector: Expression<F>,
     pub rw_counter: MpiQueries<F, N_LIMBS_RW_COUNTER>,
     pub tag_bits: [Expression<F>; 4],
@@ -56,7 +63,10 @@ pub struct Queries<F: Field> {
     pub initial_value: Expression<F>,
     pub initial_value_prev: Expression<F>,
     pub is_non_exist: Expression<F>,
+    pub is_code_hash: Expression<F>,
     pub lookups: LookupsQueries<F>,
+    // (current, previous) little endian bytes of the word value
+    pub value_bytes: Option<(RlcQueries<F, N_BYTES_WORD>, RlcQueries<F, N_BYTES_WORD>)>,
     pub power_of_randomness: [Expression<F>; N_BYTES_WORD - 1],
     pub first_different_limb: [Expression<F>; 4],
     pub not_first_access: Expression<F>,
@@ -148,6 +158,16 @@ impl<F: Field> ConstraintBuilder<F> {
                 * q.first_different_limb[3].clone(),
         );
 
+        if let Some((bytes, _)) = &q.value_bytes {
+            self.condition(q.is_word_value(), |cb| {
+                cb.require_equal(
+                    "value matches its bytes",
+                    q.value(),
+                    bytes.rlc(&q.power_of_randomness),
+                );
+            });
+        }
+
         // When at least one of the keys (tag, id, address, field_tag, or storage_key)
         // in the current row differs from the previous row.
         self.condition(q.first_access(), |cb| {
@@ -159,10 +179,29 @@ impl<F: Field> ConstraintBuilder<F> {
 
         // When all the keys in the current row and previous row are equal.
         self.condition(q.not_first_access.clone(), |cb| {
-            cb.require_zero(
-                "non-first access reads don't change value",
-                q.is_read() * (q.rw_table.value.clone() - q.rw_table.value_prev.clone()),
-            );
+            if let Some((bytes, bytes_prev)) = &q.value_bytes {
+                // Word values are compared byte by byte, the rest by value.
+                let is_word_value = q.is_word_value();
+                cb.condition(is_word_value.clone(), |cb| {
+                    for (byte, byte_prev) in bytes.bytes.iter().zip(bytes_prev.bytes.iter()) {
+                        cb.require_zero(
+                            "non-first access reads don't change value (byte-wise)",
+                            q.is_read() * (byte.clone() - byte_prev.clone()),
+                        );
+                    }
+                });
+                cb.condition(not::expr(is_word_value), |cb| {
+                    cb.require_zero(
+                        "non-first access reads don't change value",
+                        q.is_read() * (q.rw_table.value.clone() - q.rw_table.value_prev.clone()),
+                    );
+                });
+            } else {
+                cb.require_zero(
+                    "non-first access reads don't change value",
+                    q.is_read() * (q.rw_table.value.clone() - q.rw_table.value_prev.clone()),
+                );
+            }
             cb.require_zero(
                 "initial value doesn't change in an access group",
                 q.initial_value.clone() - q.initial_value_prev(),
@@ -357,6 +396,30 @@ impl<F: Field> ConstraintBuilder<F> {
                 ],
             );
         });
+
+        // (field_tag - Nonce) * (field_tag - Balance) * (field_tag - NonExisting) is -2
+        // for CodeHash and 0 for the other account fields.
+        self.require_zero(
+            "is_code_hash is 1 for CodeHash and 0 otherwise",
+            2.expr() * q.is_code_hash()
+                + (q.field_tag() - AccountFieldTag::Nonce.expr())
+                    * (q.field_tag() - AccountFieldTag::Balance.expr())
+                    * (q.field_tag() - AccountFieldTag::NonExisting.expr()),
+        );
+        // A code hash of 0, for an account that doesn't exist, matches the
+        // all-zero row of the bytecode table.
+        self.condition(q.is_code_hash(), |cb| {
+            cb.add_lookup(
+                "code_hash exists in bytecode circuit for Account CodeHash",
+                vec![
+                    (q.value(), q.bytecode_table.code_hash.clone()),
+                    (
+                        BytecodeFieldTag::Length.expr(),
+                        q.bytecode_table.tag.clone(),
+                    ),
+                ],
+            );
+        });
     }
 
     fn build_account_destructed_constraints(&mut self, q: &Queries<F>) {
@@ -499,10 +562,19 @@ impl<F: Field> Queries<F> {
         self.is_non_exist.clone()
     }
 
+    fn is_code_hash(&self) -> Expression<F> {
+        self.is_code_hash.clone()
+    }
+
     fn tag_matches(&self, tag: RwTableTag) -> Expression<F> {
         BinaryNumberConfig::<RwTableTag, 4>::value_equals_expr(tag, self.tag_bits.clone())
     }
 
+    // 1 for the rows whose value is a RLC encoded word, 0 otherwise
+    fn is_word_value(&self) -> Expression<F> {
+        self.tag_matches(RwTableTag::Stack) + self.tag_matches(RwTableTag::AccountStorage)
+    }
+
     fn first_access(&self) -> Expression<F> {
         not::expr(self.not_first_access.clone())
     }
@@ -22,10 +22,19 @@ pub struct Queries<F: Field, const N: usize> {
 
 impl<F: Field, const N: usize> Queries<F, N> {
     pub fn new(meta: &mut VirtualCells<'_, F>, c: Config<N>) -> Self {
+        Self::new_at(meta, c, Rotation::cur())
+    }
+
+    pub fn new_at(meta: &mut VirtualCells<'_, F>, c: Config<N>, rotation: Rotation) -> Self {
         Self {
-            bytes: c.bytes.map(|byte| meta.query_advice(byte, Rotation::cur())),
+            bytes: c.bytes.map(|byte| meta.query_advice(byte, rotation)),
         }
     }
+
+    /// The value encoded by the bytes
+    pub fn rlc(&self, power_of_randomness: &[Expression<F>]) -> Expression<F> {
+        rlc::expr(&self.bytes, power_of_randomness)
+    }
 }
 
 impl<const N: usize> Config<N> {
@@ -62,6 +71,7 @@ impl<F: Field, const N: usize> Chip<F, N> {
         }
     }
 
+    /// Configure byte columns encoded in the `encoded` column.
     pub fn configure(
         meta: &mut ConstraintSystem<F>,
         selector: Column<Fixed>,
@@ -69,6 +79,21 @@ impl<F: Field, const N: usize> Chip<F, N> {
         lookup: lookups::Config,
         power_of_randomness: [Expression<F>; 31],
     ) -> Config<N> {
+        let config = Self::configure_bytes(meta, lookup);
+
+        meta.create_gate("rlc encoded value matches bytes", |meta| {
+            let selector = meta.query_fixed(selector, Rotation::cur());
+            let encoded = meta.query_advice(encoded, Rotation::cur());
+            let bytes = Queries::new(meta, config);
+            vec![selector * (encoded - bytes.rlc(&power_of_randomness))]
+        });
+
+        config
+    }
+
+    /// Configure range checked byte columns only, for values that are not
+    /// always encoded in the same column.
+    pub fn configure_bytes(meta: &mut ConstraintSystem<F>, lookup: lookups::Config) -> Config<N> {
         let bytes = [0; N].map(|_| meta.advice_column());
 
         for &byte in &bytes {
@@ -77,13 +102,6 @@ impl<F: Field, const N: usize> Chip<F, N> {
             });
         }
 
-        meta.create_gate("rlc encoded value matches bytes", |meta| {
-            let selector = meta.query_fixed(selector, Rotation::cur());
-            let encoded = meta.query_advice(encoded, Rotation::cur());
-            let bytes = bytes.map(|c| meta.query_advice(c, Rotation::cur()));
-            vec![selector * (encoded - rlc::expr(&bytes, &power_of_randomness))]
-        });
-
         Config { bytes }
     }
 
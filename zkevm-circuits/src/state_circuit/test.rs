@@ -1,8 +1,9 @@
-use super::{StateCircuit, StateCircuitConfig};
+use super::{StateCircuit, StateCircuitConfig, ValueEquality};
 use crate::{
     table::{AccountFieldTag, CallContextFieldTag, RwTableTag, TxLogFieldTag, TxReceiptFieldTag},
-    util::SubCircuit,
-    witness::{MptUpdates, Rw, RwMap},
+    test_util::{debug_dump_failure, DEBUG_DUMP_ROWS},
+    util::{Challenges, SubCircuit},
+    witness::{Bytecode, MptUpdates, Rw, RwMap},
 };
 use bus_mapping::operation::{
     MemoryOp, Operation, OperationContainer, RWCounter, StackOp, StorageOp, RW,
@@ -10,14 +11,15 @@ use bus_mapping::operation::{
 use eth_types::{
     address,
     evm_types::{MemoryAddress, StackAddress},
-    Address, Field, ToAddress, Word, U256,
+    word, Address, Field, ToAddress, Word, U256,
 };
 use gadgets::binary_number::AsBits;
 use halo2_proofs::poly::kzg::commitment::ParamsKZG;
 use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner},
     dev::{MockProver, VerifyFailure},
     halo2curves::bn256::{Bn256, Fr},
-    plonk::{keygen_vk, Advice, Circuit, Column, ConstraintSystem},
+    plonk::{keygen_vk, Advice, Circuit, Column, ConstraintSystem, Error},
 };
 use rand::SeedableRng;
 use std::collections::{BTreeSet, HashMap};
@@ -34,6 +36,7 @@ pub enum AdviceColumn {
     StorageKey,
     StorageKeyByte0,
     StorageKeyByte1,
+    FieldTag,
     Value,
     RwCounter,
     RwCounterLimb0,
@@ -49,6 +52,7 @@ pub enum AdviceColumn {
     LimbIndexBit3,
     LimbIndexBit4, // least significant bit
     InitialValue,
+    IsCodeHash,
 }
 
 impl AdviceColumn {
@@ -61,6 +65,7 @@ impl AdviceColumn {
             Self::StorageKey => config.rw_table.storage_key,
             Self::StorageKeyByte0 => config.sort_keys.storage_key.bytes[0],
             Self::StorageKeyByte1 => config.sort_keys.storage_key.bytes[1],
+            Self::FieldTag => config.rw_table.field_tag,
             Self::Value => config.rw_table.value,
             Self::RwCounter => config.rw_table.rw_counter,
             Self::RwCounterLimb0 => config.sort_keys.rw_counter.limbs[0],
@@ -76,6 +81,7 @@ impl AdviceColumn {
             Self::LimbIndexBit3 => config.lexicographic_ordering.first_different_limb.bits[3],
             Self::LimbIndexBit4 => config.lexicographic_ordering.first_different_limb.bits[4],
             Self::InitialValue => config.initial_value,
+            Self::IsCodeHash => config.is_code_hash,
         }
     }
 }
@@ -449,6 +455,87 @@ fn address_limb_out_of_range() {
     assert_error_matches(result, "mpi limb fits into u16");
 }
 
+#[test]
+fn account_field_tag_out_of_range() {
+    let row = |field_tag| Rw::Account {
+        rw_counter: 1,
+        is_write: false,
+        account_address: address!("0x000000000000000000000000000000000cafe002"),
+        field_tag,
+        value: U256::zero(),
+        value_prev: U256::zero(),
+    };
+    for field_tag in AccountFieldTag::iter() {
+        assert_eq!(verify(vec![row(field_tag)]), Ok(()), "{:?}", field_tag);
+    }
+
+    // The MPT proof type of a storage change isn't an account field.
+    let overrides = HashMap::from([((AdviceColumn::FieldTag, 0), Fr::from(6))]);
+    let errors = verify_with_overrides(vec![row(AccountFieldTag::Nonce)], overrides)
+        .expect_err("result is not an error");
+
+    // The sort keys and the MPT lookup also break, only look for the range
+    // check.
+    assert!(
+        errors.iter().any(|error| matches!(
+            error,
+            VerifyFailure::ConstraintNotSatisfied { constraint, .. }
+                if format!("{}", constraint).contains("field_tag in AccountFieldTag range")
+        )),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn account_code_hash_in_bytecode_table() {
+    let bytecode = Bytecode::new(vec![0x60, 0x01, 0x00]);
+    let row = |code_hash| Rw::Account {
+        rw_counter: 1,
+        is_write: false,
+        account_address: address!("0x000000000000000000000000000000000cafe002"),
+        field_tag: AccountFieldTag::CodeHash,
+        value: code_hash,
+        value_prev: code_hash,
+    };
+
+    assert_eq!(
+        verify_with_value_equality(
+            vec![row(bytecode.hash)],
+            ValueEquality::Rlc,
+            Some(vec![bytecode.clone()]),
+        ),
+        Ok(())
+    );
+    // The bytecode circuit proves a different hash than the account's one.
+    assert_error_matches(
+        verify_with_value_equality(
+            vec![row(bytecode.hash + U256::one())],
+            ValueEquality::Rlc,
+            Some(vec![bytecode]),
+        ),
+        "code_hash exists in bytecode circuit for Account CodeHash",
+    );
+}
+
+#[test]
+fn is_code_hash_mismatch() {
+    let rows = vec![Rw::Account {
+        rw_counter: 1,
+        is_write: false,
+        account_address: address!("0x000000000000000000000000000000000cafe002"),
+        field_tag: AccountFieldTag::CodeHash,
+        value: U256::zero(),
+        value_prev: U256::zero(),
+    }];
+    let overrides = HashMap::from([((AdviceColumn::IsCodeHash, 0), Fr::zero())]);
+
+    assert_error_matches(
+        verify_with_overrides(rows, overrides),
+        "is_code_hash is 1 for CodeHash and 0 otherwise",
+    );
+}
+
 #[test]
 fn storage_key_mismatch() {
     let rows = vec![Rw::AccountStorage {
@@ -532,6 +619,55 @@ fn nonlexicographic_order_tag() {
     assert_error_matches(verify(vec![second, first]), "limb_difference fits into u16");
 }
 
+#[test]
+fn debug_dump_failure_neighbouring_rows() {
+    let rows = vec![
+        Rw::Memory {
+            rw_counter: 12,
+            is_write: false,
+            call_id: 1,
+            memory_address: 10,
+            byte: 0,
+        },
+        Rw::Memory {
+            rw_counter: 40,
+            is_write: false,
+            call_id: 1,
+            memory_address: 10,
+            byte: 200,
+        },
+    ];
+
+    let n_rows = rows.len();
+    let prover = prover(rows, HashMap::new());
+    let errors = prover
+        .verify_at_rows(N_ROWS - n_rows..N_ROWS, N_ROWS - n_rows..N_ROWS)
+        .expect_err("result is not an error");
+    let dump = debug_dump_failure(&prover, &errors[0]);
+
+    let failing_row: usize = dump
+        .lines()
+        .find_map(|line| line.strip_prefix("> row "))
+        .and_then(|line| line.split(':').next())
+        .expect("failing row is not marked")
+        .parse()
+        .unwrap();
+    for row in failing_row - DEBUG_DUMP_ROWS..failing_row {
+        assert!(dump.contains(&format!("  row {}:", row)), "{}", dump);
+    }
+    // The rw_counter of the previous read is dumped on the row above.
+    let previous_row = format!("  row {}: ", failing_row - 1);
+    let previous_cells = dump
+        .lines()
+        .find_map(|line| line.strip_prefix(&previous_row))
+        .expect("previous row is not dumped");
+    assert!(
+        previous_cells.contains(&format!("{:?}", Fr::from(12))),
+        "{}",
+        dump
+    );
+}
+
 #[test]
 fn nonlexicographic_order_field_tag() {
     let first = Rw::CallContext {
@@ -576,6 +712,7 @@ fn nonlexicographic_order_id() {
 
 #[test]
 fn nonlexicographic_order_address() {
+    let bytecode = Bytecode::new(vec![0x60, 0x01, 0x00]);
     let first = Rw::Account {
         rw_counter: 50,
         is_write: true,
@@ -589,9 +726,11 @@ fn nonlexicographic_order_address() {
         is_write: true,
         account_address: address!("0x2000000000000000000000000000000000000000"),
         field_tag: AccountFieldTag::CodeHash,
-        value: U256::one(),
-        value_prev: U256::one(),
+        value: bytecode.hash,
+        value_prev: bytecode.hash,
     };
+    let verify =
+        |rows| verify_with_value_equality(rows, ValueEquality::Rlc, Some(vec![bytecode.clone()]));
 
     assert_eq!(verify(vec![first, second]), Ok(()));
     assert_error_matches(verify(vec![second, first]), "limb_difference fits into u16");
@@ -730,6 +869,90 @@ fn read_inconsistency() {
     assert_error_matches(verify(rows), "non-first access reads don't change value");
 }
 
+#[test]
+fn value_equality_modes() {
+    let stack_rows = |read_value: u64| {
+        vec![
+            Rw::Stack {
+                rw_counter: 1,
+                is_write: true,
+                call_id: 1,
+                stack_pointer: 1023,
+                value: U256::from(0x1234),
+            },
+            Rw::Stack {
+                rw_counter: 2,
+                is_write: false,
+                call_id: 1,
+                stack_pointer: 1023,
+                value: U256::from(read_value),
+            },
+        ]
+    };
+
+    for value_equality in [ValueEquality::Rlc, ValueEquality::ByteWise] {
+        assert_eq!(
+            verify_with_value_equality(stack_rows(0x1234), value_equality, None),
+            Ok(()),
+            "{:?}",
+            value_equality
+        );
+        assert_error_matches(
+            verify_with_value_equality(stack_rows(0x1235), value_equality, None),
+            "non-first access reads don't change value",
+        );
+    }
+}
+
+#[test]
+fn value_bytes_known_word() {
+    let value = word!("0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20");
+    let rows = vec![
+        Rw::Stack {
+            rw_counter: 1,
+            is_write: true,
+            call_id: 1,
+            stack_pointer: 1023,
+            value,
+        },
+        Rw::Stack {
+            rw_counter: 2,
+            is_write: false,
+            call_id: 1,
+            stack_pointer: 1023,
+            value,
+        },
+    ];
+
+    assert_eq!(
+        verify_with_value_equality(rows, ValueEquality::ByteWise, None),
+        Ok(())
+    );
+}
+
+#[test]
+fn storage_key_bytes_little_endian() {
+    let rows = vec![Rw::AccountStorage {
+        rw_counter: 1,
+        is_write: false,
+        account_address: Address::default(),
+        storage_key: word!("0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"),
+        value: U256::from(34),
+        value_prev: U256::from(34),
+        tx_id: 4,
+        committed_value: U256::from(34),
+    }];
+    // Both bytes are in range, but in the big endian order.
+    let overrides = HashMap::from([
+        ((AdviceColumn::StorageKeyByte0, 0), Fr::from(0x1f)),
+        ((AdviceColumn::StorageKeyByte1, 0), Fr::from(0x20)),
+    ]);
+
+    let result = verify_with_overrides(rows, overrides);
+
+    assert_error_matches(result, "rlc encoded value matches bytes");
+}
+
 #[test]
 fn all_padding() {
     assert_eq!(
@@ -829,6 +1052,20 @@ fn invalid_stack_address() {
     assert_error_matches(verify(rows), "stack address fits into 10 bits");
 }
 
+#[test]
+fn stack_address_out_of_range() {
+    // Stack addresses are in [0, 1024)
+    let rows = vec![Rw::Stack {
+        rw_counter: 9,
+        is_write: true,
+        call_id: 3,
+        stack_pointer: 1024,
+        value: U256::from(10),
+    }];
+
+    assert_error_matches(verify(rows), "stack address fits into 10 bits");
+}
+
 #[test]
 fn invalid_stack_address_change() {
     let rows = vec![
@@ -854,6 +1091,35 @@ fn invalid_stack_address_change() {
     );
 }
 
+#[test]
+fn stack_pointer_jump() {
+    let rows = |call_ids: [usize; 2]| {
+        vec![
+            Rw::Stack {
+                rw_counter: 9,
+                is_write: true,
+                call_id: call_ids[0],
+                stack_pointer: 100,
+                value: U256::from(10),
+            },
+            Rw::Stack {
+                rw_counter: 10,
+                is_write: true,
+                call_id: call_ids[1],
+                stack_pointer: 105,
+                value: U256::from(20),
+            },
+        ]
+    };
+
+    assert_error_matches(
+        verify(rows([3, 3])),
+        "if previous row is also Stack with unchanged call id, address change is 0 or 1",
+    );
+    // The stack of another call starts anew.
+    assert_eq!(verify(rows([3, 4])), Ok(()));
+}
+
 #[test]
 fn invalid_tags() {
     let first_row_offset = -isize::try_from(N_ROWS).unwrap();
@@ -985,6 +1251,7 @@ fn prover(rows: Vec<Rw>, overrides: HashMap<(AdviceColumn, isize), Fr>) -> MockP
     let circuit = StateCircuit::<Fr> {
         rows,
         updates,
+        bytecodes: Vec::new(),
         overrides,
         n_rows: N_ROWS,
         _marker: std::marker::PhantomData::default(),
@@ -994,6 +1261,53 @@ fn prover(rows: Vec<Rw>, overrides: HashMap<(AdviceColumn, isize), Fr>) -> MockP
     MockProver::<Fr>::run(17, &circuit, power_of_randomness).unwrap()
 }
 
+/// StateCircuit configured with `ValueEquality::ByteWise`
+#[derive(Default)]
+struct ByteWiseStateCircuit(StateCircuit<Fr>);
+
+impl Circuit<Fr> for ByteWiseStateCircuit {
+    type Config = (StateCircuitConfig<Fr>, Challenges);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        StateCircuit::configure_with_value_equality(meta, ValueEquality::ByteWise)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<Fr>) -> Result<(), Error> {
+        self.0.synthesize(config, layouter)
+    }
+}
+
+fn verify_with_value_equality(
+    rows: Vec<Rw>,
+    value_equality: ValueEquality,
+    bytecodes: Option<Vec<Bytecode>>,
+) -> Result<(), Vec<VerifyFailure>> {
+    let used_rows = rows.len();
+    let updates = MptUpdates::mock_from(&rows);
+    let circuit = StateCircuit::<Fr> {
+        rows,
+        updates,
+        bytecodes: bytecodes.unwrap_or_default(),
+        overrides: HashMap::new(),
+        n_rows: N_ROWS,
+        _marker: std::marker::PhantomData::default(),
+    };
+    let power_of_randomness = circuit.instance();
+    let prover = match value_equality {
+        ValueEquality::Rlc => MockProver::<Fr>::run(17, &circuit, power_of_randomness),
+        ValueEquality::ByteWise => {
+            MockProver::<Fr>::run(17, &ByteWiseStateCircuit(circuit), power_of_randomness)
+        }
+    }
+    .unwrap();
+    prover.verify_at_rows(N_ROWS - used_rows..N_ROWS, N_ROWS - used_rows..N_ROWS)
+}
+
 fn verify(rows: Vec<Rw>) -> Result<(), Vec<VerifyFailure>> {
     let used_rows = rows.len();
     prover(rows, HashMap::new())
@@ -61,7 +61,9 @@ use crate::keccak_circuit::keccak_packed_multi::{
     KeccakCircuit, KeccakCircuitConfig, KeccakCircuitConfigArgs,
 };
 use crate::pi_circuit::{PiCircuit, PiCircuitConfig, PiCircuitConfigArgs};
-use crate::state_circuit::{StateCircuit, StateCircuitConfig, StateCircuitConfigArgs};
+use crate::state_circuit::{
+    StateCircuit, StateCircuitConfig, StateCircuitConfigArgs, ValueEquality,
+};
 use crate::table::{
     BlockTable, BytecodeTable, CopyTable, ExpTable, KeccakTable, MptTable, RwTable, TxTable,
 };
@@ -104,6 +106,70 @@ pub struct SuperCircuitConfig<
     exp_circuit: ExpCircuitConfig<F>,
 }
 
+/// Selection of the sub-circuits of the [`SuperCircuit`] that are
+/// synthesized, to bisect a failing super circuit by turning some of them off.
+/// All the sub-circuits are always configured, so that the layout and the
+/// shared randomness don't depend on the selection, but a disabled one assigns
+/// no row: its selectors stay off, which trivially satisfies its constraints.
+/// The lookups of an enabled sub-circuit into a table loaded by a disabled
+/// one still fail, e.g. the EVM circuit needs the State circuit for the Rw
+/// table, and the State circuit needs the Bytecode circuit for the code hashes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubCircuitSelection {
+    /// EVM Circuit
+    pub evm: bool,
+    /// State Circuit
+    pub state: bool,
+    /// Tx Circuit
+    pub tx: bool,
+    /// Public Input Circuit
+    pub pi: bool,
+    /// Bytecode Circuit
+    pub bytecode: bool,
+    /// Copy Circuit
+    pub copy: bool,
+    /// Exp Circuit
+    pub exp: bool,
+    /// Keccak Circuit
+    pub keccak: bool,
+}
+
+impl SubCircuitSelection {
+    /// All the sub-circuits enabled.
+    pub const fn all() -> Self {
+        Self {
+            evm: true,
+            state: true,
+            tx: true,
+            pi: true,
+            bytecode: true,
+            copy: true,
+            exp: true,
+            keccak: true,
+        }
+    }
+
+    /// All the sub-circuits disabled.
+    pub const fn none() -> Self {
+        Self {
+            evm: false,
+            state: false,
+            tx: false,
+            pi: false,
+            bytecode: false,
+            copy: false,
+            exp: false,
+            keccak: false,
+        }
+    }
+}
+
+impl Default for SubCircuitSelection {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
 /// The Super Circuit contains all the zkEVM circuits
 #[derive(Clone, Default, Debug)]
 pub struct SuperCircuit<
@@ -128,6 +194,8 @@ pub struct SuperCircuit<
     pub exp_circuit: ExpCircuit<F>,
     /// Keccak Circuit
     pub keccak_circuit: KeccakCircuit<F>,
+    /// Sub-circuits that are synthesized
+    pub enabled: SubCircuitSelection,
 }
 
 impl<F: Field, const MAX_TXS: usize, const MAX_CALLDATA: usize, const MAX_RWS: usize>
@@ -224,7 +292,9 @@ impl<F: Field, const MAX_TXS: usize, const MAX_CALLDATA: usize, const MAX_RWS: u
             StateCircuitConfigArgs {
                 rw_table,
                 mpt_table,
+                bytecode_table: bytecode_table.clone(),
                 challenges,
+                value_equality: ValueEquality::Rlc,
             },
         );
         let exp_circuit = ExpCircuitConfig::new(meta, exp_table);
@@ -278,25 +348,44 @@ impl<F: Field, const MAX_TXS: usize, const MAX_CALLDATA: usize, const MAX_RWS: u
             Value::known(block.randomness),
         )?;
 
-        self.keccak_circuit
-            .synthesize_sub(&config.keccak_circuit, &challenges, &mut layouter)?;
-        self.bytecode_circuit.synthesize_sub(
-            &config.bytecode_circuit,
-            &challenges,
-            &mut layouter,
-        )?;
-        self.tx_circuit
-            .synthesize_sub(&config.tx_circuit, &challenges, &mut layouter)?;
-        self.state_circuit
-            .synthesize_sub(&config.state_circuit, &challenges, &mut layouter)?;
-        self.copy_circuit
-            .synthesize_sub(&config.copy_circuit, &challenges, &mut layouter)?;
-        self.exp_circuit
-            .synthesize_sub(&config.exp_circuit, &challenges, &mut layouter)?;
-        self.evm_circuit
-            .synthesize_sub(&config.evm_circuit, &challenges, &mut layouter)?;
-        self.pi_circuit
-            .synthesize_sub(&config.pi_circuit, &challenges, &mut layouter)?;
+        if self.enabled.keccak {
+            self.keccak_circuit.synthesize_sub(
+                &config.keccak_circuit,
+                &challenges,
+                &mut layouter,
+            )?;
+        }
+        if self.enabled.bytecode {
+            self.bytecode_circuit.synthesize_sub(
+                &config.bytecode_circuit,
+                &challenges,
+                &mut layouter,
+            )?;
+        }
+        if self.enabled.tx {
+            self.tx_circuit
+                .synthesize_sub(&config.tx_circuit, &challenges, &mut layouter)?;
+        }
+        if self.enabled.state {
+            self.state_circuit
+                .synthesize_sub(&config.state_circuit, &challenges, &mut layouter)?;
+        }
+        if self.enabled.copy {
+            self.copy_circuit
+                .synthesize_sub(&config.copy_circuit, &challenges, &mut layouter)?;
+        }
+        if self.enabled.exp {
+            self.exp_circuit
+                .synthesize_sub(&config.exp_circuit, &challenges, &mut layouter)?;
+        }
+        if self.enabled.evm {
+            self.evm_circuit
+                .synthesize_sub(&config.evm_circuit, &challenges, &mut layouter)?;
+        }
+        if self.enabled.pi {
+            self.pi_circuit
+                .synthesize_sub(&config.pi_circuit, &challenges, &mut layouter)?;
+        }
         Ok(())
     }
 }
@@ -366,12 +455,50 @@ impl<F: Field, const MAX_TXS: usize, const MAX_CALLDATA: usize, const MAX_RWS: u
             copy_circuit,
             exp_circuit,
             keccak_circuit,
+            enabled: SubCircuitSelection::all(),
         };
 
+        circuit.check_randomness()?;
+
         let instance = circuit.instance();
         Ok((k, circuit, instance))
     }
 
+    /// Only synthesize the sub-circuits of `enabled`, the other ones are left
+    /// unassigned.
+    pub fn with_sub_circuits(mut self, enabled: SubCircuitSelection) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Check that all the sub-circuits holding a randomness use the one of the
+    /// EVM Circuit block, which is the one the challenges are built from in
+    /// `synthesize`.  A mismatch would otherwise only show up as an opaque
+    /// verification failure.
+    pub fn check_randomness(&self) -> Result<(), bus_mapping::Error> {
+        let randomness = self
+            .evm_circuit
+            .block
+            .as_ref()
+            .map(|block| block.randomness);
+        let sub_circuit_randomness = [
+            self.copy_circuit
+                .block
+                .as_ref()
+                .map(|block| block.randomness),
+            Some(self.pi_circuit.randomness),
+        ];
+        if sub_circuit_randomness
+            .iter()
+            .any(|sub_randomness| *sub_randomness != randomness)
+        {
+            return Err(bus_mapping::Error::InternalError(
+                "sub-circuits are configured with different randomness",
+            ));
+        }
+        Ok(())
+    }
+
     /// Returns suitable inputs for the SuperCircuit.
     pub fn instance(&self) -> Vec<Vec<F>> {
         // SignVerifyChip -> ECDSAChip -> MainGate instance column
@@ -518,6 +645,39 @@ mod super_circuit_tests {
         block
     }
 
+    #[test]
+    fn super_circuit_randomness_mismatch() {
+        let (_, mut circuit, _, _) = SuperCircuit::<Fr, 1, 32, 256>::build(block_1tx()).unwrap();
+        assert_eq!(circuit.check_randomness(), Ok(()));
+
+        circuit.pi_circuit.randomness = Fr::from(MOCK_RANDOMNESS + 1);
+        assert_eq!(
+            circuit.check_randomness(),
+            Err(bus_mapping::Error::InternalError(
+                "sub-circuits are configured with different randomness"
+            ))
+        );
+    }
+
+    // High memory usage test.  Run in serial with:
+    // `cargo test [...] serial_ -- --ignored --test-threads 1`
+    #[ignore]
+    #[test]
+    fn serial_test_super_circuit_only_state() {
+        let (k, circuit, instance, _) = SuperCircuit::<Fr, 1, 32, 256>::build(block_1tx()).unwrap();
+        assert_eq!(circuit.enabled, SubCircuitSelection::all());
+        // The code hashes read by the State circuit are looked up in the
+        // bytecode table, which is loaded by the Bytecode circuit.
+        let circuit = circuit.with_sub_circuits(SubCircuitSelection {
+            state: true,
+            bytecode: true,
+            ..SubCircuitSelection::none()
+        });
+
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        assert_eq!(prover.verify_par(), Ok(()));
+    }
+
     // High memory usage test.  Run in serial with:
     // `cargo test [...] serial_ -- --ignored --test-threads 1`
     #[ignore]
@@ -22,6 +22,7 @@ use halo2_proofs::{
 use halo2_proofs::{circuit::Layouter, plonk::*, poly::Rotation};
 use itertools::Itertools;
 use keccak256::plain::Keccak;
+use serde::{Deserialize, Serialize};
 use std::array;
 use strum_macros::{EnumCount, EnumIter};
 
@@ -83,6 +84,9 @@ pub enum TxFieldTag {
     /// TxSignHash: Hash of the transaction without the signature, used for
     /// signing.
     TxSignHash,
+    /// SigV: Signature v of the transaction, which embeds the chain id since
+    /// EIP-155.
+    SigV,
     /// CallData
     CallData,
 }
@@ -194,7 +198,7 @@ impl<F: Field> LookupTable<F> for TxTable {
 }
 
 /// Tag to identify the operation type in a RwTable row
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum RwTableTag {
     /// Start (used for padding)
     Start = 1,
@@ -245,7 +249,7 @@ impl From<RwTableTag> for usize {
 }
 
 /// Tag for an AccountField in RwTable
-#[derive(Clone, Copy, Debug, EnumIter, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, EnumIter, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AccountFieldTag {
     /// Nonce field
     Nonce = 1,
@@ -259,7 +263,7 @@ pub enum AccountFieldTag {
 impl_expr!(AccountFieldTag);
 
 /// Tag for a TxLogField in RwTable
-#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
 pub enum TxLogFieldTag {
     /// Address field
     Address = 1,
@@ -271,7 +275,7 @@ pub enum TxLogFieldTag {
 impl_expr!(TxLogFieldTag);
 
 /// Tag for a TxReceiptField in RwTable
-#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, EnumCount)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, EnumCount, Serialize, Deserialize)]
 pub enum TxReceiptFieldTag {
     /// Tx result
     PostStateOrStatus = 1,
@@ -283,7 +287,7 @@ pub enum TxReceiptFieldTag {
 impl_expr!(TxReceiptFieldTag);
 
 /// Tag for a CallContextField in RwTable
-#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
 pub enum CallContextFieldTag {
     /// RwCounterEndOfReversion
     RwCounterEndOfReversion = 1,
@@ -884,6 +888,37 @@ pub struct CopyTable {
 type CopyTableRow<F> = [(Value<F>, &'static str); 8];
 type CopyCircuitRow<F> = [(Value<F>, &'static str); 4];
 
+/// Progress of the assignments generation of a [`CopyEvent`], taken between
+/// two copied bytes, to resume it with [`CopyTable::resume_assignments`].
+#[derive(Clone, Copy, Debug)]
+pub struct CopyCheckpoint<F> {
+    /// Source address of the next byte to copy
+    pub src_addr: u64,
+    /// Destination address of the next byte to copy
+    pub dst_addr: u64,
+    /// Number of bytes left to copy
+    pub bytes_left: u64,
+    /// RLC of the bytes copied so far, when copying to [`CopyDataType::RlcAcc`]
+    value_acc: Value<F>,
+}
+
+impl<F: Field> CopyCheckpoint<F> {
+    /// Checkpoint before the first byte of `copy_event`.
+    pub fn start(copy_event: &CopyEvent) -> Self {
+        Self {
+            src_addr: copy_event.src_addr,
+            dst_addr: copy_event.dst_addr,
+            bytes_left: copy_event.length(),
+            value_acc: Value::known(F::zero()),
+        }
+    }
+
+    /// Returns true if all the bytes have been copied.
+    pub fn is_done(&self) -> bool {
+        self.bytes_left == 0
+    }
+}
+
 impl CopyTable {
     /// Construct a new CopyTable
     pub fn construct<F: Field>(meta: &mut ConstraintSystem<F>, q_enable: Column<Fixed>) -> Self {
@@ -905,6 +940,32 @@ impl CopyTable {
         copy_event: &CopyEvent,
         challenges: Challenges<Value<F>>,
     ) -> Vec<(CopyDataType, CopyTableRow<F>, CopyCircuitRow<F>)> {
+        let (assignments, _) = Self::resume_assignments(
+            copy_event,
+            challenges,
+            CopyCheckpoint::start(copy_event),
+            copy_event.bytes.len(),
+        );
+        assignments
+    }
+
+    /// Generate the copy table and copy circuit assignments of at most
+    /// `n_bytes` bytes of a copy event from `checkpoint`, returning them with
+    /// the checkpoint to resume from.
+    pub fn resume_assignments<F: Field>(
+        copy_event: &CopyEvent,
+        challenges: Challenges<Value<F>>,
+        checkpoint: CopyCheckpoint<F>,
+        n_bytes: usize,
+    ) -> (
+        Vec<(CopyDataType, CopyTableRow<F>, CopyCircuitRow<F>)>,
+        CopyCheckpoint<F>,
+    ) {
+        let bytes_copied = copy_event.length() - checkpoint.bytes_left;
+        debug_assert_eq!(checkpoint.src_addr, copy_event.src_addr + bytes_copied);
+        debug_assert_eq!(checkpoint.dst_addr, copy_event.dst_addr + bytes_copied);
+        let n_bytes = std::cmp::min(n_bytes as u64, checkpoint.bytes_left);
+
         let mut assignments = Vec::new();
         // rlc_acc
         let rlc_acc = if copy_event.dst_type == CopyDataType::RlcAcc {
@@ -919,7 +980,7 @@ impl CopyTable {
         } else {
             Value::known(F::zero())
         };
-        let mut value_acc = Value::known(F::zero());
+        let mut value_acc = checkpoint.value_acc;
         for (step_idx, (is_read_step, copy_step)) in copy_event
             .bytes
             .iter()
@@ -943,6 +1004,8 @@ impl CopyTable {
                 once((true, read_step)).chain(once((false, write_step)))
             })
             .enumerate()
+            .skip(bytes_copied as usize * 2)
+            .take(n_bytes as usize * 2)
         {
             // is_first
             let is_first = Value::known(if step_idx == 0 { F::one() } else { F::zero() });
@@ -990,7 +1053,7 @@ impl CopyTable {
             };
 
             // bytes_left
-            let bytes_left = u64::try_from(copy_event.bytes.len() * 2 - step_idx).unwrap() / 2;
+            let bytes_left = copy_event.bytes_left(step_idx);
             // value
             let value = if copy_event.dst_type == CopyDataType::RlcAcc {
                 if is_read_step {
@@ -1040,7 +1103,13 @@ impl CopyTable {
                 ],
             ));
         }
-        assignments
+        let checkpoint = CopyCheckpoint {
+            src_addr: checkpoint.src_addr + n_bytes,
+            dst_addr: checkpoint.dst_addr + n_bytes,
+            bytes_left: checkpoint.bytes_left - n_bytes,
+            value_acc,
+        };
+        (assignments, checkpoint)
     }
 
     /// Assign the `CopyTable` from a `Block`.
@@ -7,12 +7,14 @@ use crate::{
 };
 use bus_mapping::{circuit_input_builder::CircuitsParams, mock::BlockData};
 use eth_types::geth_types::{GethData, Transaction};
+use eth_types::Field;
 use ethers_core::types::{NameOrAddress, TransactionRequest};
 use ethers_signers::{LocalWallet, Signer};
-use halo2_proofs::dev::{MockProver, VerifyFailure};
+use halo2_proofs::dev::{CellValue, FailureLocation, MockProver, VerifyFailure};
 use halo2_proofs::halo2curves::bn256::Fr;
 use mock::TestContext;
 use rand::{CryptoRng, Rng};
+use std::fmt::Write;
 
 #[cfg(test)]
 #[ctor::ctor]
@@ -109,7 +111,8 @@ pub fn test_circuits_witness_block(
     // state circuit and evm circuit must be same
     if config.enable_state_circuit_test {
         const N_ROWS: usize = 1 << 16;
-        let state_circuit = StateCircuit::<Fr>::new(block.rws, N_ROWS);
+        let state_circuit = StateCircuit::<Fr>::new(block.rws, N_ROWS)
+            .with_bytecodes(block.bytecodes.into_values());
         let power_of_randomness = state_circuit.instance();
         let prover = MockProver::<Fr>::run(18, &state_circuit, power_of_randomness).unwrap();
         // Skip verification of Start rows to accelerate testing
@@ -127,6 +130,84 @@ pub fn test_circuits_witness_block(
     Ok(())
 }
 
+/// Number of rows printed on each side of a failing row by
+/// [`debug_dump_failure`].
+pub const DEBUG_DUMP_ROWS: usize = 3;
+
+/// Returns the row a failure points at.  Rows inside a region are given by
+/// their offset, which is the circuit row for circuits assigning a single
+/// region from row 0, like the EVM and state circuits.
+fn failure_row(failure: &VerifyFailure) -> Option<usize> {
+    let location = match failure {
+        VerifyFailure::ConstraintNotSatisfied { location, .. } => location,
+        VerifyFailure::Lookup { location, .. } => location,
+        VerifyFailure::CellNotAssigned { offset, .. } => return usize::try_from(*offset).ok(),
+        _ => return None,
+    };
+    match location {
+        FailureLocation::InRegion { offset, .. } => Some(*offset),
+        FailureLocation::OutsideRegion { row } => Some(*row),
+    }
+}
+
+/// Formats a failure followed by the witness of the [`DEBUG_DUMP_ROWS`] rows
+/// around the failing row, see [`debug_dump_failure_with_rows`].
+pub fn debug_dump_failure<F: Field>(prover: &MockProver<F>, failure: &VerifyFailure) -> String {
+    debug_dump_failure_with_rows(prover, failure, DEBUG_DUMP_ROWS)
+}
+
+/// Formats a failure followed by the witness of the `rows` rows on each side
+/// of the failing row. Only the advice and fixed columns holding a non-zero
+/// value in those rows are printed, named as `advice[index]` and
+/// `fixed[index]`, and the failing row is marked with `>`.
+pub fn debug_dump_failure_with_rows<F: Field>(
+    prover: &MockProver<F>,
+    failure: &VerifyFailure,
+    rows: usize,
+) -> String {
+    let mut dump = format!("{}\n", failure);
+    let row = match failure_row(failure) {
+        Some(row) => row,
+        None => return dump,
+    };
+
+    let columns = prover
+        .advice()
+        .iter()
+        .enumerate()
+        .map(|(index, values)| (format!("advice[{}]", index), values))
+        .chain(
+            prover
+                .fixed()
+                .iter()
+                .enumerate()
+                .map(|(index, values)| (format!("fixed[{}]", index), values)),
+        );
+    let n_rows = prover.advice().first().map_or(0, |values| values.len());
+    let window = row.saturating_sub(rows)..(row + rows + 1).min(n_rows);
+    let columns: Vec<_> = columns
+        .filter(|(_, values)| {
+            values[window.clone()]
+                .iter()
+                .any(|value| matches!(value, CellValue::Assigned(v) if *v != F::zero()))
+        })
+        .collect();
+
+    for current in window {
+        let marker = if current == row { '>' } else { ' ' };
+        let cells: Vec<_> = columns
+            .iter()
+            .map(|(name, values)| match &values[current] {
+                CellValue::Unassigned => format!("{} = unassigned", name),
+                CellValue::Assigned(value) => format!("{} = {:?}", name, value),
+                CellValue::Poison(index) => format!("{} = poison({})", name, index),
+            })
+            .collect();
+        writeln!(dump, "{} row {}: {}", marker, current, cells.join(", ")).unwrap();
+    }
+    dump
+}
+
 /// generate rand tx for pi circuit
 pub fn rand_tx<R: Rng + CryptoRng>(mut rng: R, chain_id: u64, has_calldata: bool) -> Transaction {
     let wallet0 = LocalWallet::new(&mut rng).with_chain_id(chain_id);
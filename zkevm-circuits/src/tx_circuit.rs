@@ -6,6 +6,7 @@
 
 pub mod sign_verify;
 
+use crate::evm_circuit::util::constraint_builder::BaseConstraintBuilder;
 use crate::table::{KeccakTable, TxFieldTag, TxTable};
 use crate::util::{random_linear_combine_word as rlc, Challenges, SubCircuit, SubCircuitConfig};
 use crate::witness;
@@ -14,9 +15,11 @@ use eth_types::{
     sign_types::SignData,
     {geth_types::Transaction, Address, Field, ToLittleEndian, ToScalar},
 };
+use gadgets::util::{select, Expr};
 use halo2_proofs::{
     circuit::{AssignedCell, Layouter, Region, SimpleFloorPlanner, Value},
-    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+    poly::Rotation,
 };
 use itertools::Itertools;
 use log::error;
@@ -34,10 +37,10 @@ pub use halo2_proofs::halo2curves::{
 
 /// Number of static fields per tx: [nonce, gas, gas_price,
 /// caller_address, callee_address, is_create, value, call_data_length,
-/// call_data_gas_cost, tx_sign_hash].
+/// call_data_gas_cost, tx_sign_hash, sig_v].
 /// Note that call data bytes are layed out in the TxTable after all the static
 /// fields arranged by txs.
-pub(crate) const TX_LEN: usize = 10;
+pub(crate) const TX_LEN: usize = 11;
 
 /// Config for TxCircuit
 #[derive(Clone, Debug)]
@@ -47,6 +50,12 @@ pub struct TxCircuitConfig<F: Field> {
     index: Column<Advice>,
     value: Column<Advice>,
     sign_verify: SignVerifyConfig,
+    // Signature v of each tx, decoded against the circuit's chain_id
+    q_sig_v: Selector,
+    sig_v: Column<Advice>,
+    recovery_id: Column<Advice>,
+    is_eip155: Column<Advice>,
+    chain_id: Column<Fixed>,
     _marker: PhantomData<F>,
     // External tables
     keccak_table: KeccakTable,
@@ -82,12 +91,45 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
 
         let sign_verify = SignVerifyConfig::new(meta, keccak_table.clone(), challenges);
 
+        let q_sig_v = meta.selector();
+        let sig_v = meta.advice_column();
+        meta.enable_equality(sig_v);
+        let recovery_id = meta.advice_column();
+        let is_eip155 = meta.advice_column();
+        let chain_id = meta.fixed_column();
+
+        meta.create_gate("tx sig v encodes the chain_id", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let sig_v = meta.query_advice(sig_v, Rotation::cur());
+            let recovery_id = meta.query_advice(recovery_id, Rotation::cur());
+            let is_eip155 = meta.query_advice(is_eip155, Rotation::cur());
+            let chain_id = meta.query_fixed(chain_id, Rotation::cur());
+
+            cb.require_boolean("recovery_id is boolean", recovery_id.clone());
+            cb.require_boolean("is_eip155 is boolean", is_eip155.clone());
+            // v = chain_id * 2 + 35 + recovery_id since EIP-155, and
+            // v = 27 + recovery_id before.
+            cb.require_equal(
+                "sig v = recovery_id + (chain_id * 2 + 35 or 27)",
+                sig_v,
+                recovery_id + select::expr(is_eip155, chain_id * 2.expr() + 35.expr(), 27.expr()),
+            );
+
+            cb.gate(meta.query_selector(q_sig_v))
+        });
+
         Self {
             tx_id,
             tag,
             index,
             value,
             sign_verify,
+            q_sig_v,
+            sig_v,
+            recovery_id,
+            is_eip155,
+            chain_id,
             keccak_table,
             _marker: PhantomData,
         }
@@ -132,6 +174,54 @@ impl<F: Field> TxCircuitConfig<F> {
         region.assign_advice(|| "value", self.value, offset, || value)
     }
 
+    /// Assigns the signature v of each tx, with its decoded recovery id and
+    /// whether it follows EIP-155, against `chain_id`.  Returns the sig_v
+    /// cells, to be copied into the tx table.
+    fn assign_sig_v(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        txs: &[Transaction],
+        chain_id: u64,
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        layouter.assign_region(
+            || "tx sig v",
+            |mut region| {
+                let mut assigned_sig_vs = Vec::with_capacity(txs.len());
+                for (offset, tx) in txs.iter().enumerate() {
+                    let (recovery_id, tx_chain_id) = tx.decode_v().map_err(|e| {
+                        error!("decode_v error for tx {:?}", e);
+                        Error::Synthesis
+                    })?;
+                    self.q_sig_v.enable(&mut region, offset)?;
+                    region.assign_fixed(
+                        || "chain_id",
+                        self.chain_id,
+                        offset,
+                        || Value::known(F::from(chain_id)),
+                    )?;
+                    assigned_sig_vs.push(region.assign_advice(
+                        || "sig_v",
+                        self.sig_v,
+                        offset,
+                        || Value::known(F::from(tx.v)),
+                    )?);
+                    for (name, column, value) in [
+                        ("recovery_id", self.recovery_id, recovery_id as u64),
+                        ("is_eip155", self.is_eip155, tx_chain_id.is_some() as u64),
+                    ] {
+                        region.assign_advice(
+                            || name,
+                            column,
+                            offset,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(assigned_sig_vs)
+            },
+        )
+    }
+
     /// Get number of rows required.
     pub fn get_num_rows_required(num_tx: usize) -> usize {
         let num_rows_range_table = 1 << 18;
@@ -181,6 +271,7 @@ impl<F: Field> TxCircuit<F> {
         challenges: &Challenges<Value<F>>,
         layouter: &mut impl Layouter<F>,
         assigned_sig_verifs: Vec<AssignedSignatureVerify<F>>,
+        assigned_sig_vs: Vec<AssignedCell<F, F>>,
     ) -> Result<(), Error> {
         layouter.assign_region(
             || "tx table",
@@ -237,7 +328,7 @@ impl<F: Field> TxCircuit<F> {
                         ),
                         (
                             TxFieldTag::IsCreate,
-                            Value::known(F::from(tx.to.is_none() as u64)),
+                            Value::known(F::from(tx.is_create() as u64)),
                         ),
                         (
                             TxFieldTag::Value,
@@ -262,6 +353,7 @@ impl<F: Field> TxCircuit<F> {
                             TxFieldTag::TxSignHash,
                             assigned_sig_verif.msg_hash_rlc.value().copied(),
                         ),
+                        (TxFieldTag::SigV, Value::known(F::from(tx.v))),
                     ] {
                         let assigned_cell =
                             config.assign_row(&mut region, offset, i + 1, tag, 0, value)?;
@@ -278,6 +370,9 @@ impl<F: Field> TxCircuit<F> {
                                 assigned_cell.cell(),
                                 assigned_sig_verif.msg_hash_rlc.cell(),
                             )?,
+                            // The v decoded against the chain_id in the "tx sig v" region
+                            TxFieldTag::SigV if i < assigned_sig_vs.len() => region
+                                .constrain_equal(assigned_cell.cell(), assigned_sig_vs[i].cell())?,
                             _ => (),
                         }
                     }
@@ -365,7 +460,14 @@ impl<F: Field> SubCircuit<F> for TxCircuit<F> {
         let assigned_sig_verifs =
             self.sign_verify
                 .assign(&config.sign_verify, layouter, &sign_datas, challenges)?;
-        self.assign_tx_table(config, challenges, layouter, assigned_sig_verifs)?;
+        let assigned_sig_vs = config.assign_sig_v(layouter, &self.txs, self.chain_id)?;
+        self.assign_tx_table(
+            config,
+            challenges,
+            layouter,
+            assigned_sig_verifs,
+            assigned_sig_vs,
+        )?;
         Ok(())
     }
 }
@@ -423,6 +525,7 @@ mod tx_circuit_tests {
     use super::*;
     use crate::util::log2_ceil;
     use eth_types::address;
+    use ethers_core::types::TransactionRequest;
     use halo2_proofs::{
         dev::{MockProver, VerifyFailure},
         halo2curves::bn256::Fr,
@@ -493,6 +596,67 @@ mod tx_circuit_tests {
         assert_eq!(run::<Fr>(vec![tx], chain_id, MAX_TXS, MAX_CALLDATA), Ok(()));
     }
 
+    #[test]
+    fn tx_eip155_chain_id() {
+        let chain_id: u64 = mock::MOCK_CHAIN_ID.as_u64();
+
+        let tx: Transaction = mock::CORRECT_MOCK_TXS[0].clone().into();
+        let (_, tx_chain_id) = tx.decode_v().unwrap();
+        assert_eq!(tx_chain_id, Some(chain_id));
+        // The chain id embedded in the signature must match the circuit's one.
+        assert!(tx.sign_data(chain_id + 1).is_err());
+    }
+
+    #[test]
+    fn tx_circuit_1tx_1max_tx_pre_eip155() {
+        const MAX_TXS: usize = 1;
+        const MAX_CALLDATA: usize = 32;
+
+        let chain_id: u64 = mock::MOCK_CHAIN_ID.as_u64();
+
+        let mock_tx = &mock::CORRECT_MOCK_TXS[0];
+        let mut tx: Transaction = mock_tx.clone().into();
+        // Sign the transaction without a chain id.
+        let req: TransactionRequest = (&tx).into();
+        let sig = mock_tx.from.as_wallet().sign_hash(req.sighash());
+        tx.v = sig.v;
+        tx.r = sig.r;
+        tx.s = sig.s;
+        assert!(matches!(tx.decode_v(), Ok((_, None))));
+
+        assert_eq!(run::<Fr>(vec![tx], chain_id, MAX_TXS, MAX_CALLDATA), Ok(()));
+    }
+
+    #[test]
+    fn tx_circuit_1tx_1max_tx_bad_chain_id() {
+        const MAX_TXS: usize = 1;
+        const MAX_CALLDATA: usize = 32;
+
+        let chain_id: u64 = mock::MOCK_CHAIN_ID.as_u64();
+
+        let mock_tx = &mock::CORRECT_MOCK_TXS[0];
+        let mut tx: Transaction = mock_tx.clone().into();
+        // Sign the transaction for another chain, whose id ends up in v.
+        let req: TransactionRequest = (&tx).into();
+        let sig = mock_tx
+            .from
+            .as_wallet()
+            .sign_hash(req.chain_id(chain_id + 1).sighash());
+        tx.v = sig.v - 27 + 35 + 2 * (chain_id + 1);
+        tx.r = sig.r;
+        tx.s = sig.s;
+        assert_eq!(tx.decode_v().unwrap().1, Some(chain_id + 1));
+
+        // The tx is signed for another chain, so the circuit can't be
+        // synthesized for this one.
+        let k = log2_ceil(NUM_BLINDING_ROWS + TxCircuit::<Fr>::min_num_rows(MAX_TXS, MAX_CALLDATA));
+        let circuit = TxCircuit::<Fr>::new(MAX_TXS, MAX_CALLDATA, chain_id, vec![tx]);
+        assert!(matches!(
+            MockProver::run(k, &circuit, vec![vec![]]),
+            Err(Error::Synthesis)
+        ));
+    }
+
     #[test]
     fn tx_circuit_bad_address() {
         const MAX_TXS: usize = 1;
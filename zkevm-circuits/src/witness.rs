@@ -11,7 +11,7 @@ pub use call::Call;
 mod mpt;
 pub use mpt::{MptUpdate, MptUpdateRow, MptUpdates};
 mod rw;
-pub use rw::{Rw, RwMap, RwRow};
+pub use rw::{Rw, RwCounterAnomaly, RwMap, RwMapDecodeError, RwRow, RW_MAP_FORMAT_VERSION};
 mod step;
 pub use step::ExecStep;
 mod tx;
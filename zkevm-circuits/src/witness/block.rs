@@ -1,13 +1,19 @@
 use std::collections::HashMap;
 
 use crate::{evm_circuit::util::RandomLinearCombination, table::BlockContextFieldTag};
+#[cfg(any(feature = "test", test))]
+use bus_mapping::mock::BlockData;
 use bus_mapping::{
     circuit_input_builder::{self, CircuitsParams, CopyEvent, ExpEvent},
     Error,
 };
+#[cfg(any(feature = "test", test))]
+use eth_types::geth_types::GethData;
 use eth_types::{Address, Field, ToLittleEndian, ToScalar, Word};
 
-use super::{step::step_convert, tx::tx_convert, Bytecode, ExecStep, RwMap, Transaction};
+use super::{
+    step::step_convert, tx::tx_convert, Bytecode, ExecStep, RwCounterAnomaly, RwMap, Transaction,
+};
 
 // TODO: Remove fields that are duplicated in`eth_block`
 /// Block is the struct used by all circuits, which contains all the needed
@@ -52,6 +58,56 @@ pub struct Block<F> {
     pub eth_block: eth_types::Block<eth_types::Transaction>,
 }
 
+impl<F: Field> Block<F> {
+    /// Build the witness Block directly from geth types, without a geth RPC
+    /// client: the block with its transactions, their execution traces and the
+    /// accounts they touch.  Only available for tests, as it goes through the
+    /// [`BlockData`] mock.
+    #[cfg(any(feature = "test", test))]
+    pub fn from_geth_data(
+        geth_data: GethData,
+        circuits_params: CircuitsParams,
+    ) -> Result<Self, Error> {
+        let block_data = BlockData::new_from_geth_data_with_params(geth_data, circuits_params);
+        let mut builder = block_data.new_circuit_input_builder();
+        builder.handle_block(&block_data.eth_block, &block_data.geth_traces)?;
+        block_convert(&builder.block, &builder.code_db)
+    }
+
+    /// Returns the first and last rw_counters of the rw rows, or `(0, 0)` when
+    /// there are none.  Padding rows aren't counted.
+    pub fn rw_counter_range(&self) -> (u64, u64) {
+        self.rws.rw_counter_range()
+    }
+
+    /// Check the rw_counters of the rw rows form the continuous sequence
+    /// starting from 1 the circuits rely on, returning the first gap or
+    /// duplicate otherwise.
+    pub fn validate_rw_counters(&self) -> Result<(), RwCounterAnomaly> {
+        self.rws.validate_rw_counters()
+    }
+
+    /// Returns the copy events of the block, one per line as printed by their
+    /// [`Display`](std::fmt::Display), in the order of the copy table.
+    pub fn dump_copy_events(&self) -> String {
+        self.copy_events
+            .iter()
+            .map(|event| format!("{}\n", event))
+            .collect()
+    }
+
+    /// Returns the depth of the deepest call of the block, where root calls
+    /// have depth 1, or 0 when the block has no transactions.
+    pub fn max_call_depth(&self) -> usize {
+        self.txs
+            .iter()
+            .flat_map(|tx| tx.calls.iter())
+            .map(|call| call.depth)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
 /// Block context for execution
 #[derive(Debug, Default, Clone)]
 pub struct BlockContext {
@@ -197,3 +253,126 @@ pub fn block_convert<F: Field>(
         eth_block: block.eth_block.clone(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::RwTableTag;
+    use eth_types::{bytecode, evm_types::OpcodeId, ToWord};
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use mock::{TestContext, MOCK_ACCOUNTS};
+
+    #[test]
+    fn block_from_geth_data() {
+        let code = bytecode! {
+            PUSH1(0x01)
+            PUSH1(0x02)
+            ADD
+            STOP
+        };
+        let geth_data: GethData = TestContext::<2, 1>::simple_ctx_with_bytecode(code.clone())
+            .unwrap()
+            .into();
+
+        let block = Block::<Fr>::from_geth_data(geth_data, CircuitsParams::default()).unwrap();
+
+        assert_eq!(block.txs.len(), 1);
+        assert!(block
+            .bytecodes
+            .contains_key(&Bytecode::new(code.to_vec()).hash));
+        assert_eq!(
+            block.txs[0]
+                .steps
+                .iter()
+                .filter_map(|step| step.opcode)
+                .collect::<Vec<_>>(),
+            vec![
+                OpcodeId::PUSH1,
+                OpcodeId::PUSH1,
+                OpcodeId::ADD,
+                OpcodeId::STOP
+            ]
+        );
+    }
+
+    #[test]
+    fn block_rw_counter_gap() {
+        let code = bytecode! {
+            PUSH1(0x01)
+            PUSH1(0x02)
+            ADD
+            STOP
+        };
+        let geth_data: GethData = TestContext::<2, 1>::simple_ctx_with_bytecode(code)
+            .unwrap()
+            .into();
+        let mut block = Block::<Fr>::from_geth_data(geth_data, CircuitsParams::default()).unwrap();
+        assert_eq!(block.validate_rw_counters(), Ok(()));
+        let (first, last) = block.rw_counter_range();
+        assert_eq!(first, 1);
+
+        // Duplicating the last stack row reuses its rw_counter.
+        let stack = block.rws.0.get_mut(&RwTableTag::Stack).unwrap();
+        let duplicate = stack.last().unwrap().clone();
+        let duplicate_rw_counter = duplicate.rw_counter() as u64;
+        stack.push(duplicate);
+        assert_eq!(
+            block.validate_rw_counters(),
+            Err(RwCounterAnomaly::Duplicate(duplicate_rw_counter))
+        );
+
+        // Removing the first stack row leaves a gap at its rw_counter.
+        let stack = block.rws.0.get_mut(&RwTableTag::Stack).unwrap();
+        stack.pop();
+        let removed_rw_counter = stack.remove(0).rw_counter() as u64;
+        assert!(removed_rw_counter > first && removed_rw_counter < last);
+        assert_eq!(
+            block.validate_rw_counters(),
+            Err(RwCounterAnomaly::Gap {
+                expected: removed_rw_counter,
+                found: removed_rw_counter + 1,
+            })
+        );
+        assert_eq!(block.rw_counter_range(), (first, last));
+    }
+
+    #[test]
+    fn block_max_call_depth() {
+        let code_b = bytecode! {
+            STOP
+        };
+        // code A calls code B.
+        let code_a = bytecode! {
+            PUSH1(0x00) // retLength
+            PUSH1(0x00) // retOffset
+            PUSH1(0x00) // argsLength
+            PUSH1(0x00) // argsOffset
+            PUSH1(0x00) // value
+            PUSH32(MOCK_ACCOUNTS[1].to_word()) // addr
+            PUSH32(0x1_0000) // gas
+            CALL
+            STOP
+        };
+        let geth_data: GethData = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(MOCK_ACCOUNTS[0]).code(code_a);
+                accs[1].address(MOCK_ACCOUNTS[1]).code(code_b);
+                accs[2]
+                    .address(MOCK_ACCOUNTS[2])
+                    .balance(Word::from(1u64 << 30));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[2].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+        let block = Block::<Fr>::from_geth_data(geth_data, CircuitsParams::default()).unwrap();
+
+        assert_eq!(block.txs[0].calls.len(), 2);
+        assert_eq!(block.max_call_depth(), 2);
+        assert_eq!(Block::<Fr>::default().max_call_depth(), 0);
+    }
+}
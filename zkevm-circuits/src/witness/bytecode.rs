@@ -4,7 +4,8 @@ use halo2_proofs::circuit::Value;
 use sha3::{Digest, Keccak256};
 
 use crate::{
-    evm_circuit::util::RandomLinearCombination, table::BytecodeFieldTag, util::Challenges,
+    bytecode_circuit::bytecode_unroller::BytecodeRow, evm_circuit::util::RandomLinearCombination,
+    table::BytecodeFieldTag, util::Challenges,
 };
 
 /// Bytecode
@@ -48,7 +49,7 @@ impl Bytecode {
 
             push_data_left = if is_code {
                 // push_data_left will be > 0 only if it is a push opcode
-                OpcodeId::from(*byte).data_len()
+                OpcodeId::from(*byte).push_data_len()
             } else {
                 push_data_left - 1
             };
@@ -64,6 +65,41 @@ impl Bytecode {
         rows
     }
 
+    /// Rows of the bytecode in the layout expected by the bytecode circuit: a
+    /// first row holding the code length followed by one row per byte, tagged
+    /// as opcode or push data.
+    pub(crate) fn to_circuit_rows<F: Field>(&self) -> Vec<BytecodeRow<F>> {
+        let mut rows = Vec::with_capacity(1 + self.bytes.len());
+        rows.push(BytecodeRow {
+            code_hash: self.hash,
+            tag: F::from(BytecodeFieldTag::Length as u64),
+            index: F::zero(),
+            is_code: F::zero(),
+            value: F::from(self.bytes.len() as u64),
+        });
+
+        let mut push_data_left = 0;
+        for (idx, byte) in self.bytes.iter().enumerate() {
+            let is_code = push_data_left == 0;
+
+            push_data_left = if is_code {
+                // push_data_left will be > 0 only if it is a push opcode
+                OpcodeId::from(*byte).push_data_len()
+            } else {
+                push_data_left - 1
+            };
+
+            rows.push(BytecodeRow {
+                code_hash: self.hash,
+                tag: F::from(BytecodeFieldTag::Byte as u64),
+                index: F::from(idx as u64),
+                is_code: F::from(is_code as u64),
+                value: F::from(*byte as u64),
+            });
+        }
+        rows
+    }
+
     /// get byte value and is_code pair
     pub fn get(&self, dest: usize) -> [u8; 2] {
         let mut push_data_left = 0;
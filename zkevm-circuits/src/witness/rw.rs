@@ -1,10 +1,11 @@
 #![allow(missing_docs)]
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use bus_mapping::operation::{self, AccountField, CallContextField, TxLogField, TxReceiptField};
 use eth_types::{Address, Field, ToAddress, ToLittleEndian, ToScalar, Word, U256};
 use halo2_proofs::circuit::Value;
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use crate::util::build_tx_log_address;
 use crate::{
@@ -13,7 +14,7 @@ use crate::{
 };
 
 /// Rw constainer for a witness block
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct RwMap(pub HashMap<RwTableTag, Vec<Rw>>);
 
 impl std::ops::Index<(RwTableTag, usize)> for RwMap {
@@ -23,7 +24,104 @@ impl std::ops::Index<(RwTableTag, usize)> for RwMap {
         &self.0.get(&tag).unwrap()[idx]
     }
 }
+/// Anomaly in the sequence of rw_counters of a [`RwMap`], which must be
+/// continuous and start from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RwCounterAnomaly {
+    /// The rw_counter `expected` is missing, the next one being `found`
+    Gap { expected: u64, found: u64 },
+    /// The rw_counter is used by more than one row
+    Duplicate(u64),
+}
+
+/// Version of the format of [`RwMap::to_json`], to be bumped whenever
+/// [`Rw`] changes in a way that breaks the decoding of older blobs.
+pub const RW_MAP_FORMAT_VERSION: u32 = 1;
+
+/// Header of a serialized [`RwMap`], decoded first to check its version
+/// before the rows are.
+#[derive(Deserialize)]
+struct RwMapHeader {
+    version: u32,
+}
+
+/// A serialized [`RwMap`], as a list since JSON only has string map keys.
+#[derive(Serialize, Deserialize)]
+struct SerializedRwMap {
+    version: u32,
+    rws: Vec<(RwTableTag, Vec<Rw>)>,
+}
+
+/// Error decoding a [`RwMap`] with [`RwMap::from_json`].
+#[derive(Debug)]
+pub enum RwMapDecodeError {
+    /// The blob was serialized with format version `found`, while this crate
+    /// only decodes `expected`
+    VersionMismatch { expected: u32, found: u32 },
+    /// The blob is not a valid serialized [`RwMap`]
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for RwMapDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionMismatch { expected, found } => write!(
+                f,
+                "serialized RwMap has format version {}, but only version {} is supported",
+                found, expected
+            ),
+            Self::Json(err) => write!(f, "malformed serialized RwMap: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RwMapDecodeError {}
+
+impl From<serde_json::Error> for RwMapDecodeError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
 impl RwMap {
+    /// Returns the non padding rw_counters, sorted.
+    fn sorted_rw_counters(&self) -> Vec<u64> {
+        self.0
+            .iter()
+            .filter(|(tag, _rs)| !matches!(tag, RwTableTag::Start))
+            .flat_map(|(_tag, rs)| rs)
+            .map(|r| r.rw_counter() as u64)
+            .sorted()
+            .collect()
+    }
+    /// Returns the first and last non padding rw_counters, or `(0, 0)` when
+    /// there's no such row.
+    pub fn rw_counter_range(&self) -> (u64, u64) {
+        let rw_counters = self.sorted_rw_counters();
+        match (rw_counters.first(), rw_counters.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => (0, 0),
+        }
+    }
+    /// Check the non padding rw_counters are continuous and start from 1,
+    /// returning the first anomaly otherwise.
+    pub fn validate_rw_counters(&self) -> Result<(), RwCounterAnomaly> {
+        let mut prev = None;
+        for rw_counter in self.sorted_rw_counters() {
+            if prev == Some(rw_counter) {
+                return Err(RwCounterAnomaly::Duplicate(rw_counter));
+            }
+            let expected = prev.map_or(1, |prev| prev + 1);
+            if rw_counter != expected {
+                return Err(RwCounterAnomaly::Gap {
+                    expected,
+                    found: rw_counter,
+                });
+            }
+            prev = Some(rw_counter);
+        }
+        Ok(())
+    }
     /// Check rw_counter is continuous and starting from 1
     pub fn check_rw_counter_sanity(&self) {
         for (idx, rw_counter) in self
@@ -82,11 +180,31 @@ impl RwMap {
         });
         rows
     }
+    /// Serialize the rows to JSON, tagged with [`RW_MAP_FORMAT_VERSION`].
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&SerializedRwMap {
+            version: RW_MAP_FORMAT_VERSION,
+            rws: self.0.clone().into_iter().collect(),
+        })
+    }
+    /// Deserialize rows serialized by [`RwMap::to_json`], refusing the ones
+    /// serialized with another format version.
+    pub fn from_json(json: &str) -> Result<Self, RwMapDecodeError> {
+        let RwMapHeader { version } = serde_json::from_str(json)?;
+        if version != RW_MAP_FORMAT_VERSION {
+            return Err(RwMapDecodeError::VersionMismatch {
+                expected: RW_MAP_FORMAT_VERSION,
+                found: version,
+            });
+        }
+        let SerializedRwMap { rws, .. } = serde_json::from_str(json)?;
+        Ok(Self(rws.into_iter().collect()))
+    }
 }
 
 /// Read-write records in execution. Rws are used for connecting evm circuit and
 /// state circuits.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Rw {
     /// Start
     Start { rw_counter: usize },
@@ -846,3 +964,51 @@ impl From<&operation::OperationContainer> for RwMap {
         Self(rws)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bus_mapping::mock::BlockData;
+    use eth_types::{bytecode, geth_types::GethData};
+    use mock::TestContext;
+
+    #[test]
+    fn rw_map_json_versioned() {
+        let bytecode = bytecode! {
+            PUSH1(0x01)
+            PUSH1(0x40)
+            MSTORE
+            STOP
+        };
+        let block: GethData = TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode)
+            .unwrap()
+            .into();
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+        let rws = RwMap::from(&builder.block.container);
+
+        let json = rws.to_json().unwrap();
+        assert_eq!(RwMap::from_json(&json).unwrap(), rws);
+
+        // A blob from a newer format version is refused instead of being
+        // decoded as best as possible.
+        let mut blob: serde_json::Value = serde_json::from_str(&json).unwrap();
+        blob["version"] = (RW_MAP_FORMAT_VERSION + 1).into();
+        let err = RwMap::from_json(&blob.to_string()).unwrap_err();
+        assert!(matches!(
+            err,
+            RwMapDecodeError::VersionMismatch { expected, found }
+                if expected == RW_MAP_FORMAT_VERSION && found == RW_MAP_FORMAT_VERSION + 1
+        ));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "serialized RwMap has format version {}, but only version {} is supported",
+                RW_MAP_FORMAT_VERSION + 1,
+                RW_MAP_FORMAT_VERSION
+            )
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use bus_mapping::circuit_input_builder;
-use eth_types::{Address, Field, ToLittleEndian, ToScalar, ToWord, Word};
+use eth_types::{evm_types::HardFork, Address, Field, ToLittleEndian, ToScalar, ToWord, Word};
 use halo2_proofs::circuit::Value;
 
 use crate::{
@@ -141,10 +141,7 @@ pub(super) fn tx_convert(tx: &circuit_input_builder::Transaction, id: usize) ->
         value: tx.value,
         call_data: tx.input.clone(),
         call_data_length: tx.input.len(),
-        call_data_gas_cost: tx
-            .input
-            .iter()
-            .fold(0, |acc, byte| acc + if *byte == 0 { 4 } else { 16 }),
+        call_data_gas_cost: tx.call_data_gas_cost(HardFork::default()),
         calls: tx
             .calls()
             .iter()